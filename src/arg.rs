@@ -0,0 +1,54 @@
+use crate::{HasRawDisplayHandle, RawDisplayHandle};
+
+/// A display handle passed to an API either as a provider or as a raw handle.
+///
+/// Functions taking `impl Into<DisplayHandleArg<'a>>` accept both a reference to anything
+/// implementing [`HasRawDisplayHandle`] and a bare [`RawDisplayHandle`], without needing two
+/// entry points.
+///
+/// ```
+/// # use windle::*;
+/// struct MockWindow;
+///
+/// impl HasRawDisplayHandle for MockWindow {
+///     fn raw_display_handle(&self) -> RawDisplayHandle {
+///         RawDisplayHandle::windows()
+///     }
+/// }
+///
+/// fn connect<'a>(display: impl Into<DisplayHandleArg<'a>>) -> RawDisplayHandle {
+///     display.into().resolve()
+/// }
+///
+/// assert_eq!(connect(&MockWindow), RawDisplayHandle::windows());
+/// assert_eq!(connect(RawDisplayHandle::windows()), RawDisplayHandle::windows());
+/// ```
+#[derive(Clone, Copy)]
+pub enum DisplayHandleArg<'a> {
+    /// A provider, queried by [`resolve`](Self::resolve).
+    Borrowed(&'a dyn HasRawDisplayHandle),
+    /// A raw handle, returned as-is by [`resolve`](Self::resolve).
+    Raw(RawDisplayHandle),
+}
+
+impl DisplayHandleArg<'_> {
+    /// Returns the raw display handle.
+    pub fn resolve(&self) -> RawDisplayHandle {
+        match self {
+            DisplayHandleArg::Borrowed(display) => display.raw_display_handle(),
+            DisplayHandleArg::Raw(handle) => *handle,
+        }
+    }
+}
+
+impl<'a, T: HasRawDisplayHandle> From<&'a T> for DisplayHandleArg<'a> {
+    fn from(display: &'a T) -> Self {
+        DisplayHandleArg::Borrowed(display)
+    }
+}
+
+impl From<RawDisplayHandle> for DisplayHandleArg<'_> {
+    fn from(handle: RawDisplayHandle) -> Self {
+        DisplayHandleArg::Raw(handle)
+    }
+}
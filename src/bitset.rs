@@ -0,0 +1,68 @@
+/// Implements the set operations and `Debug` of a `u32` newtype used as a set of flags.
+///
+/// `$names` is an expression yielding `(Self, &str)` pairs, used by `Debug` to print the set as
+/// `Name(A | B)`.
+macro_rules! bitset {
+    ($name: ident, $names: expr) => {
+        impl $name {
+            /// Returns an empty set.
+            pub const fn empty() -> Self {
+                Self(0)
+            }
+
+            /// Returns the raw bits of the set.
+            pub const fn bits(self) -> u32 {
+                self.0
+            }
+
+            /// Returns `true` if no bit is set.
+            pub const fn is_empty(self) -> bool {
+                self.0 == 0
+            }
+
+            /// Returns `true` if every bit of `other` is set in `self`.
+            pub const fn contains(self, other: Self) -> bool {
+                self.0 & other.0 == other.0
+            }
+        }
+
+        impl core::ops::BitOr for $name {
+            type Output = Self;
+
+            fn bitor(self, rhs: Self) -> Self {
+                Self(self.0 | rhs.0)
+            }
+        }
+
+        impl core::ops::BitOrAssign for $name {
+            fn bitor_assign(&mut self, rhs: Self) {
+                self.0 |= rhs.0;
+            }
+        }
+
+        impl core::ops::BitAnd for $name {
+            type Output = Self;
+
+            fn bitand(self, rhs: Self) -> Self {
+                Self(self.0 & rhs.0)
+            }
+        }
+
+        impl core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(concat!(stringify!($name), "("))?;
+                let mut names = $names
+                    .into_iter()
+                    .filter(|&(flag, _)| self.contains(flag))
+                    .map(|(_, name)| name);
+                if let Some(name) = names.next() {
+                    f.write_str(name)?;
+                    for name in names {
+                        write!(f, " | {name}")?;
+                    }
+                }
+                f.write_str(")")
+            }
+        }
+    };
+}
@@ -0,0 +1,203 @@
+use core::cell::Cell;
+
+/// Defines a wrapper memoizing the handle `$trait` provides for a wrapped value, with an
+/// `invalidate()` to query it again.
+macro_rules! cached_handle {
+    ($(#[$attr: meta])* $name: ident, $trait: ident :: $method: ident -> $raw: ident) => {
+        $(#[$attr])*
+        #[derive(Debug)]
+        pub struct $name<T: $trait> {
+            inner: T,
+            cached: Cell<Option<$raw>>,
+        }
+
+        impl<T: $trait> $name<T> {
+            pub fn new(inner: T) -> Self {
+                Self {
+                    inner,
+                    cached: Cell::new(None),
+                }
+            }
+
+            /// Forgets the cached handle, so it is queried again from the wrapped value on next
+            /// use.
+            pub fn invalidate(&mut self) {
+                self.cached.set(None);
+            }
+
+            pub fn get_ref(&self) -> &T {
+                &self.inner
+            }
+
+            pub fn into_inner(self) -> T {
+                self.inner
+            }
+        }
+
+        impl<T: $trait> $trait for $name<T> {
+            fn $method(&self) -> $raw {
+                match self.cached.get() {
+                    Some(handle) => handle,
+                    None => {
+                        let handle = self.inner.$method();
+                        self.cached.set(Some(handle));
+                        handle
+                    }
+                }
+            }
+        }
+    };
+}
+
+use crate::{HasRawDisplayHandle, HasRawWindowHandle, RawDisplayHandle, RawWindowHandle};
+
+cached_handle!(
+    /// A wrapper memoizing the window handle of `T`.
+    ///
+    /// The handle is queried from `T` the first time it's needed and returned as-is afterwards, until
+    /// [`invalidate`](Self::invalidate) is called.
+    ///
+    /// Caching is only correct as long as `T` would keep returning the same handle. Platform events
+    /// that recreate the native window must be followed by [`invalidate`](Self::invalidate): e.g. the
+    /// native window of an Android app is destroyed on pause and a new one is created on resume, and
+    /// a web canvas can be replaced by the page. Win32 windows that are destroyed and recreated (see
+    /// [`RawWindowHandle::set_hwnd`]) need the same treatment.
+    ///
+    /// ```
+    /// # use core::cell::Cell;
+    /// # use windle::{CachedWindowHandle, HasRawWindowHandle, RawWindowHandle, Win32WindowHandle};
+    /// struct MockWindow {
+    ///     queries: Cell<usize>,
+    /// }
+    ///
+    /// impl HasRawWindowHandle for MockWindow {
+    ///     fn raw_window_handle(&self) -> RawWindowHandle {
+    ///         self.queries.set(self.queries.get() + 1);
+    ///         RawWindowHandle::Win32(Win32WindowHandle::empty())
+    ///     }
+    /// }
+    ///
+    /// let mut window = CachedWindowHandle::new(MockWindow { queries: Cell::new(0) });
+    /// window.raw_window_handle();
+    /// window.raw_window_handle();
+    /// assert_eq!(window.get_ref().queries.get(), 1);
+    ///
+    /// window.invalidate();
+    /// window.raw_window_handle();
+    /// assert_eq!(window.get_ref().queries.get(), 2);
+    /// ```
+    CachedWindowHandle,
+    HasRawWindowHandle::raw_window_handle -> RawWindowHandle
+);
+
+/// A wrapper capturing the window handle of `T` once, for code assuming it never changes.
+///
+/// The handle is queried when the wrapper is created and always returned as-is afterwards. With
+/// debug assertions enabled, every use also queries `T` again and asserts that the handle is
+/// still the same, so a violated assumption is caught early. It is violated whenever the native
+/// window is recreated, e.g. by toolkits that recreate the window on some resizes or display
+/// mode changes; use [`CachedWindowHandle`] to follow such changes instead.
+///
+/// ```
+/// # use windle::{FrozenWindowHandle, HasRawWindowHandle, RawWindowHandle, Win32WindowHandle};
+/// struct MockWindow(isize);
+///
+/// impl HasRawWindowHandle for MockWindow {
+///     fn raw_window_handle(&self) -> RawWindowHandle {
+///         Win32WindowHandle::from_hwnd(self.0).unwrap().into()
+///     }
+/// }
+///
+/// let window = FrozenWindowHandle::new(MockWindow(0x1000));
+/// assert_eq!(window.raw_window_handle(), MockWindow(0x1000).raw_window_handle());
+/// ```
+#[derive(Debug)]
+pub struct FrozenWindowHandle<T: HasRawWindowHandle> {
+    inner: T,
+    frozen: RawWindowHandle,
+}
+
+impl<T: HasRawWindowHandle> FrozenWindowHandle<T> {
+    pub fn new(inner: T) -> Self {
+        let frozen = inner.raw_window_handle();
+        Self { inner, frozen }
+    }
+
+    pub fn get_ref(&self) -> &T {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T: HasRawWindowHandle> HasRawWindowHandle for FrozenWindowHandle<T> {
+    fn raw_window_handle(&self) -> RawWindowHandle {
+        debug_assert_eq!(
+            self.inner.raw_window_handle(),
+            self.frozen,
+            "the window handle changed after being frozen",
+        );
+        self.frozen
+    }
+}
+
+cached_handle!(
+    /// A wrapper memoizing the display handle of `T`.
+    ///
+    /// This mirrors [`CachedWindowHandle`]: the handle is queried from `T` the first time it's
+    /// needed, until [`invalidate`](Self::invalidate) is called. Invalidate it whenever `T`
+    /// reconnects to the display server or otherwise replaces its display connection, as the cached
+    /// handle would keep referring to the old one.
+    ///
+    /// ```
+    /// # use core::cell::Cell;
+    /// # use windle::{CachedDisplayHandle, HasRawDisplayHandle, RawDisplayHandle};
+    /// struct MockDisplay {
+    ///     queries: Cell<usize>,
+    /// }
+    ///
+    /// impl HasRawDisplayHandle for MockDisplay {
+    ///     fn raw_display_handle(&self) -> RawDisplayHandle {
+    ///         self.queries.set(self.queries.get() + 1);
+    ///         RawDisplayHandle::windows()
+    ///     }
+    /// }
+    ///
+    /// let mut display = CachedDisplayHandle::new(MockDisplay { queries: Cell::new(0) });
+    /// display.raw_display_handle();
+    /// display.raw_display_handle();
+    /// assert_eq!(display.get_ref().queries.get(), 1);
+    ///
+    /// display.invalidate();
+    /// display.raw_display_handle();
+    /// assert_eq!(display.get_ref().queries.get(), 2);
+    /// ```
+    CachedDisplayHandle,
+    HasRawDisplayHandle::raw_display_handle -> RawDisplayHandle
+);
+
+#[cfg(all(test, debug_assertions))]
+mod tests {
+    use core::cell::Cell;
+
+    use super::*;
+    use crate::Win32WindowHandle;
+
+    struct MockWindow(Cell<isize>);
+
+    impl HasRawWindowHandle for MockWindow {
+        fn raw_window_handle(&self) -> RawWindowHandle {
+            Win32WindowHandle::from_hwnd(self.0.get()).unwrap().into()
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the window handle changed after being frozen")]
+    fn frozen_detects_changed_source() {
+        let window = FrozenWindowHandle::new(MockWindow(Cell::new(0x1000)));
+        window.get_ref().0.set(0x2000);
+        window.raw_window_handle();
+    }
+}
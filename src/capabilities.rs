@@ -0,0 +1,72 @@
+use crate::RawWindowHandle;
+
+/// A set of capabilities of a [`RawWindowHandle`], as returned by
+/// [`RawWindowHandle::capabilities`].
+///
+/// Flags are combined with `|` and tested with [`contains`](Self::contains).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct HandleCapabilities(u32);
+
+impl HandleCapabilities {
+    /// The handle provides the instance (module) owning the window.
+    ///
+    /// Set for [`RawWindowHandle::Win32`] when `hinstance` is non-null.
+    pub const HAS_INSTANCE: Self = Self(1 << 0);
+    /// The handle provides the visual (pixel format) of the window.
+    ///
+    /// Not set by any current variant.
+    pub const HAS_VISUAL: Self = Self(1 << 1);
+    /// Using the handle requires a separate display connection.
+    ///
+    /// Not set by any current variant: Windows handles don't need a display connection.
+    pub const NEEDS_DISPLAY: Self = Self(1 << 2);
+    /// The handle may only be used from the thread owning it, see
+    /// [`RawWindowHandleKind::is_thread_affine`](crate::RawWindowHandleKind::is_thread_affine).
+    ///
+    /// Set for [`RawWindowHandle::WinRT`].
+    pub const THREAD_AFFINE: Self = Self(1 << 3);
+}
+
+bitset!(
+    HandleCapabilities,
+    [
+        (HandleCapabilities::HAS_INSTANCE, "HAS_INSTANCE"),
+        (HandleCapabilities::HAS_VISUAL, "HAS_VISUAL"),
+        (HandleCapabilities::NEEDS_DISPLAY, "NEEDS_DISPLAY"),
+        (HandleCapabilities::THREAD_AFFINE, "THREAD_AFFINE"),
+    ]
+);
+
+impl RawWindowHandle {
+    /// Returns the capabilities of this handle. See [`HandleCapabilities`] for the meaning of each
+    /// flag per variant.
+    ///
+    /// ```
+    /// # use windle::{HandleCapabilities, RawWindowHandle, Win32WindowHandle, WinRTWindowHandle};
+    /// # let ptr = |addr: usize| core::ptr::without_provenance_mut::<core::ffi::c_void>(addr);
+    /// let handle = RawWindowHandle::from(Win32WindowHandle::from((ptr(0x1000), ptr(0x2000))));
+    /// assert_eq!(handle.capabilities(), HandleCapabilities::HAS_INSTANCE);
+    ///
+    /// let handle = RawWindowHandle::from(Win32WindowHandle::from_hwnd(0x1000).unwrap());
+    /// assert!(handle.capabilities().is_empty());
+    ///
+    /// let handle = RawWindowHandle::WinRT(WinRTWindowHandle::empty());
+    /// assert!(handle.capabilities().contains(HandleCapabilities::THREAD_AFFINE));
+    /// assert!(!handle.capabilities().contains(HandleCapabilities::NEEDS_DISPLAY));
+    /// ```
+    pub fn capabilities(&self) -> HandleCapabilities {
+        let mut capabilities = HandleCapabilities::empty();
+        if self.kind().is_thread_affine() {
+            capabilities |= HandleCapabilities::THREAD_AFFINE;
+        }
+
+        match self {
+            RawWindowHandle::Win32(handle) if !handle.hinstance.is_null() => {
+                capabilities |= HandleCapabilities::HAS_INSTANCE;
+            }
+            _ => {}
+        }
+
+        capabilities
+    }
+}
@@ -0,0 +1,79 @@
+use alloc::vec::Vec;
+
+use crate::{RawWindowHandle, RawWindowHandleKind};
+
+/// The differences between two [`RawWindowHandle`]s, as returned by [`RawWindowHandle::diff`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum HandleDiff {
+    /// Both handles are of the same kind; holds the names of the fields that differ, in
+    /// declaration order. The list is empty if the handles are equal.
+    Fields(Vec<&'static str>),
+    /// The handles are of different kinds, so their fields aren't compared.
+    VariantChanged {
+        /// The kind of the handle `diff` was called on.
+        from: RawWindowHandleKind,
+        /// The kind of the handle it was compared with.
+        to: RawWindowHandleKind,
+    },
+}
+
+impl HandleDiff {
+    /// Returns `true` if the handles are equal.
+    pub fn is_unchanged(&self) -> bool {
+        matches!(self, HandleDiff::Fields(fields) if fields.is_empty())
+    }
+}
+
+impl RawWindowHandle {
+    /// Compares this handle with `other`, e.g. to log exactly what changed when a window was
+    /// recreated.
+    ///
+    /// ```
+    /// # use windle::{HandleDiff, RawWindowHandle, RawWindowHandleKind};
+    /// # use windle::{Win32WindowHandle, WinRTWindowHandle};
+    /// # let ptr = |addr: usize| core::ptr::without_provenance_mut::<core::ffi::c_void>(addr);
+    /// let old = RawWindowHandle::from(Win32WindowHandle::from((ptr(0x1000), ptr(0x2000))));
+    /// let new = RawWindowHandle::from(Win32WindowHandle::from((ptr(0x3000), ptr(0x2000))));
+    /// assert_eq!(old.diff(&new), HandleDiff::Fields(vec!["hwnd"]));
+    /// assert!(old.diff(&old).is_unchanged());
+    ///
+    /// let winrt = RawWindowHandle::WinRT(WinRTWindowHandle::empty());
+    /// assert_eq!(
+    ///     old.diff(&winrt),
+    ///     HandleDiff::VariantChanged {
+    ///         from: RawWindowHandleKind::Win32,
+    ///         to: RawWindowHandleKind::WinRT,
+    ///     },
+    /// );
+    /// ```
+    pub fn diff(&self, other: &RawWindowHandle) -> HandleDiff {
+        // One flag per field, in the order of `RawWindowHandleKind::field_names`.
+        let changed = match (self, other) {
+            (RawWindowHandle::Win32(a), RawWindowHandle::Win32(b)) => [
+                a.hwnd != b.hwnd,
+                a.hinstance != b.hinstance,
+                a.composition_visual != b.composition_visual,
+            ],
+            (RawWindowHandle::WinRT(a), RawWindowHandle::WinRT(b)) => [
+                a.core_window != b.core_window,
+                a.swap_chain_panel != b.swap_chain_panel,
+                a.panel_index != b.panel_index,
+            ],
+            _ => {
+                return HandleDiff::VariantChanged {
+                    from: self.kind(),
+                    to: other.kind(),
+                }
+            }
+        };
+
+        let names = self.kind().field_names();
+        debug_assert_eq!(names.len(), changed.len());
+        let fields = names
+            .iter()
+            .zip(changed)
+            .filter_map(|(&name, changed)| changed.then_some(name))
+            .collect::<Vec<_>>();
+        HandleDiff::Fields(fields)
+    }
+}
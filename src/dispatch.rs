@@ -0,0 +1,67 @@
+/// Matches a [`RawWindowHandle`](crate::RawWindowHandle) or
+/// [`RawDisplayHandle`](crate::RawDisplayHandle) against the given arms, with a catch-all
+/// returning [`HandleError::NotSupported`](crate::HandleError::NotSupported).
+///
+/// Both enums are `#[non_exhaustive]`, so a `match` outside this crate needs a catch-all arm. The
+/// macro adds it for you, so variants added in future versions are reported as unsupported. Every
+/// arm must evaluate to a `Result<_, HandleError>`. This:
+///
+/// ```ignore
+/// dispatch_handle!(handle, {
+///     RawWindowHandle::Win32(handle) => Ok(handle.hwnd),
+/// })
+/// ```
+///
+/// expands to:
+///
+/// ```ignore
+/// match handle {
+///     RawWindowHandle::Win32(handle) => Ok(handle.hwnd),
+///     #[allow(unreachable_patterns)]
+///     _ => Err(HandleError::NotSupported),
+/// }
+/// ```
+///
+/// The catch-all is allowed to be unreachable, so matching every variant doesn't warn. Arms may
+/// have `if` guards.
+///
+/// ```
+/// # use windle::*;
+/// fn name(handle: RawWindowHandle) -> Result<&'static str, HandleError> {
+///     dispatch_handle!(handle, {
+///         RawWindowHandle::Win32(_) => Ok("win32"),
+///         RawWindowHandle::WinRT(_) => Ok("winrt"),
+///     })
+/// }
+///
+/// assert_eq!(name(RawWindowHandle::Win32(Win32WindowHandle::empty())), Ok("win32"));
+/// assert_eq!(name(RawWindowHandle::WinRT(WinRTWindowHandle::empty())), Ok("winrt"));
+///
+/// // A variant without an arm, as a variant added in a future version would be.
+/// fn win32_only(handle: RawWindowHandle) -> Result<&'static str, HandleError> {
+///     dispatch_handle!(handle, {
+///         RawWindowHandle::Win32(_) => Ok("win32"),
+///     })
+/// }
+///
+/// assert_eq!(
+///     win32_only(RawWindowHandle::WinRT(WinRTWindowHandle::empty())),
+///     Err(HandleError::NotSupported),
+/// );
+///
+/// let display = RawDisplayHandle::windows();
+/// let system = dispatch_handle!(display, {
+///     RawDisplayHandle::Windows(_) => Ok("windows"),
+/// });
+/// assert_eq!(system, Ok("windows"));
+/// ```
+#[macro_export]
+macro_rules! dispatch_handle {
+    ($handle:expr, { $($pattern:pat $(if $guard:expr)? => $arm:expr),* $(,)? }) => {
+        match $handle {
+            $($pattern $(if $guard)? => $arm,)*
+            #[allow(unreachable_patterns)]
+            _ => ::core::result::Result::Err($crate::HandleError::NotSupported),
+        }
+    };
+}
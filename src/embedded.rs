@@ -0,0 +1,41 @@
+use crate::{HasRawWindowHandle, RawWindowHandle};
+
+/// A window handle for a surface embedded in a parent window, e.g. a child `HWND` hosting a
+/// renderer inside an application window.
+///
+/// The wrapper provides the child's handle. The parent is informational, for compositors and
+/// tools that want to know where the surface lives; it is not used by the wrapper itself.
+///
+/// ```
+/// # use windle::{EmbeddedWindowHandle, HasRawWindowHandle, RawWindowHandle, Win32WindowHandle};
+/// let child = RawWindowHandle::from(Win32WindowHandle::from_hwnd(0x1000).unwrap());
+/// let parent = RawWindowHandle::from(Win32WindowHandle::from_hwnd(0x2000).unwrap());
+///
+/// let embedded = EmbeddedWindowHandle::new(child, Some(parent));
+/// assert_eq!(embedded.raw_window_handle(), child);
+/// assert_eq!(embedded.parent(), Some(parent));
+///
+/// assert_eq!(EmbeddedWindowHandle::new(child, None).parent(), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EmbeddedWindowHandle {
+    child: RawWindowHandle,
+    parent: Option<RawWindowHandle>,
+}
+
+impl EmbeddedWindowHandle {
+    pub fn new(child: RawWindowHandle, parent: Option<RawWindowHandle>) -> Self {
+        Self { child, parent }
+    }
+
+    /// Returns the handle of the window the surface is embedded in, if known.
+    pub fn parent(&self) -> Option<RawWindowHandle> {
+        self.parent
+    }
+}
+
+impl HasRawWindowHandle for EmbeddedWindowHandle {
+    fn raw_window_handle(&self) -> RawWindowHandle {
+        self.child
+    }
+}
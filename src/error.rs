@@ -0,0 +1,28 @@
+use core::fmt;
+
+/// An error that can occur while operating on a raw handle.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HandleError {
+    /// The operation is not supported by the handle's variant.
+    NotSupported,
+    /// The handle is not currently available (e.g. a mandatory field is null).
+    Unavailable,
+    /// The named field holds a value that can't be a valid handle.
+    InvalidField(&'static str),
+    /// The fields of the handle contradict each other; holds a description of the violated rule.
+    Inconsistent(&'static str),
+}
+
+impl fmt::Display for HandleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotSupported => f.write_str("the operation is not supported by this handle"),
+            Self::Unavailable => f.write_str("the handle is not available"),
+            Self::InvalidField(field) => write!(f, "invalid value for field `{field}`"),
+            Self::Inconsistent(rule) => write!(f, "inconsistent handle: {rule}"),
+        }
+    }
+}
+
+impl core::error::Error for HandleError {}
@@ -0,0 +1,52 @@
+use crate::{HasRawWindowHandle, RawWindowHandle};
+
+/// A colorspace a renderer is asked to present in, as carried by [`HintedWindowHandle`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorSpaceHint {
+    /// sRGB primaries with the sRGB transfer function (standard dynamic range).
+    Srgb,
+    /// sRGB primaries with a linear transfer function, as used by `scRGB` (extended dynamic range).
+    LinearSrgb,
+    /// Display P3 primaries with the sRGB transfer function.
+    DisplayP3,
+    /// Rec. 2020 primaries with the PQ (SMPTE ST 2084) transfer function, i.e. HDR10.
+    Rec2020,
+}
+
+/// A window handle shipped together with the colorspace the window should be presented in.
+///
+/// The hint is advisory: the renderer decides whether it can honor it, and the handle itself is
+/// returned unchanged.
+///
+/// ```
+/// # use windle::{ColorSpaceHint, HasRawWindowHandle, HintedWindowHandle, RawWindowHandle, Win32WindowHandle};
+/// let handle = RawWindowHandle::from(Win32WindowHandle::from_hwnd(0x1000).unwrap());
+/// let hinted = HintedWindowHandle::new(handle, Some(ColorSpaceHint::Rec2020));
+/// assert_eq!(hinted.raw_window_handle(), handle);
+/// assert_eq!(hinted.colorspace(), Some(ColorSpaceHint::Rec2020));
+///
+/// assert_eq!(HintedWindowHandle::new(handle, None).colorspace(), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HintedWindowHandle {
+    handle: RawWindowHandle,
+    colorspace: Option<ColorSpaceHint>,
+}
+
+impl HintedWindowHandle {
+    pub fn new(handle: RawWindowHandle, colorspace: Option<ColorSpaceHint>) -> Self {
+        Self { handle, colorspace }
+    }
+
+    /// Returns the requested colorspace, or `None` if the window has no preference.
+    pub fn colorspace(&self) -> Option<ColorSpaceHint> {
+        self.colorspace
+    }
+}
+
+impl HasRawWindowHandle for HintedWindowHandle {
+    fn raw_window_handle(&self) -> RawWindowHandle {
+        self.handle
+    }
+}
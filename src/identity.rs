@@ -0,0 +1,100 @@
+use crate::RawWindowHandle;
+
+impl RawWindowHandle {
+    /// Returns a 128-bit key identifying the surface this handle refers to.
+    ///
+    /// The [index](crate::RawWindowHandleKind::index) of the handle's kind is stored in the high 32
+    /// bits and the address of its primary native value in the low 64 bits:
+    ///
+    /// | Variant                    | Primary value                                          |
+    /// |----------------------------|--------------------------------------------------------|
+    /// | [`RawWindowHandle::Win32`] | `hwnd`                                                 |
+    /// | [`RawWindowHandle::WinRT`] | `core_window`, or `swap_chain_panel` if it is null     |
+    ///
+    /// Equal handles have equal identities, and handles of different kinds never share one.
+    ///
+    /// ```
+    /// # use windle::{RawWindowHandle, Win32WindowHandle, WinRTWindowHandle};
+    /// # let ptr = core::ptr::without_provenance_mut::<core::ffi::c_void>(0x1000);
+    /// let win32 = RawWindowHandle::from(Win32WindowHandle::from_generic(ptr).unwrap());
+    /// let same = RawWindowHandle::from(Win32WindowHandle::from_generic(ptr).unwrap());
+    /// assert_eq!(win32.identity(), same.identity());
+    ///
+    /// let winrt = RawWindowHandle::from(WinRTWindowHandle::empty().with_core_window(ptr));
+    /// assert_ne!(win32.identity(), winrt.identity());
+    /// ```
+    pub fn identity(&self) -> u128 {
+        let primary = match self {
+            RawWindowHandle::Win32(handle) => handle.hwnd,
+            RawWindowHandle::WinRT(handle) if handle.core_window.is_null() => {
+                handle.swap_chain_panel
+            }
+            RawWindowHandle::WinRT(handle) => handle.core_window,
+        };
+
+        ((self.kind().index() as u128) << 96) | primary.addr() as u128
+    }
+
+    /// Returns `true` if both handles likely refer to the same surface through an OS-stable
+    /// identifier, even if they come from different processes.
+    ///
+    /// | Variant                    | Identifier                                           |
+    /// |----------------------------|------------------------------------------------------|
+    /// | [`RawWindowHandle::Win32`] | `hwnd`, which is the same value in every process     |
+    /// | [`RawWindowHandle::WinRT`] | none: COM pointers are only meaningful in-process    |
+    ///
+    /// Handles without a stable identifier, null handles and handles of different kinds never
+    /// alias.
+    ///
+    /// ```
+    /// # use windle::{RawWindowHandle, Win32WindowHandle, WinRTWindowHandle};
+    /// # let ptr = |addr: usize| core::ptr::without_provenance_mut::<core::ffi::c_void>(addr);
+    /// let ours = RawWindowHandle::from(Win32WindowHandle::from((ptr(0x1000), ptr(0x2000))));
+    /// let theirs = RawWindowHandle::from(Win32WindowHandle::from((ptr(0x1000), ptr(0x3000))));
+    /// assert!(ours.aliases(&theirs));
+    ///
+    /// let winrt = RawWindowHandle::from(WinRTWindowHandle::empty().with_core_window(ptr(0x1000)));
+    /// assert!(!winrt.aliases(&winrt));
+    /// assert!(!ours.aliases(&winrt));
+    /// ```
+    pub fn aliases(&self, other: &RawWindowHandle) -> bool {
+        match (self, other) {
+            (RawWindowHandle::Win32(a), RawWindowHandle::Win32(b)) => {
+                !a.hwnd.is_null() && a.hwnd.addr() == b.hwnd.addr()
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns a key ordering handles by kind, then by [identity](Self::identity).
+    ///
+    /// The first element is the [tag](Self::tag) of the handle, so sorting by this key groups
+    /// handles of the same kind together, in the order of
+    /// [`RawWindowHandleKind::ALL`](crate::RawWindowHandleKind::ALL). For id-based handles the
+    /// order would be stable across runs; all current variants are pointer-based, so the order
+    /// within a kind depends on addresses and is only meaningful within one process.
+    ///
+    /// ```
+    /// # use windle::{RawWindowHandle, RawWindowHandleKind, Win32WindowHandle, WinRTWindowHandle};
+    /// # let ptr = |addr: usize| core::ptr::without_provenance_mut::<core::ffi::c_void>(addr);
+    /// let mut handles = vec![
+    ///     RawWindowHandle::from(WinRTWindowHandle::empty().with_core_window(ptr(0x1000))),
+    ///     RawWindowHandle::from(Win32WindowHandle::from_hwnd(0x3000).unwrap()),
+    ///     RawWindowHandle::from(WinRTWindowHandle::empty().with_core_window(ptr(0x4000))),
+    ///     RawWindowHandle::from(Win32WindowHandle::from_hwnd(0x2000).unwrap()),
+    /// ];
+    /// handles.sort_by_key(RawWindowHandle::sort_key);
+    ///
+    /// let kinds: Vec<_> = handles.iter().map(RawWindowHandle::kind).collect();
+    /// assert_eq!(kinds, [
+    ///     RawWindowHandleKind::Win32,
+    ///     RawWindowHandleKind::Win32,
+    ///     RawWindowHandleKind::WinRT,
+    ///     RawWindowHandleKind::WinRT,
+    /// ]);
+    /// assert_eq!(handles[0], RawWindowHandle::from(Win32WindowHandle::from_hwnd(0x2000).unwrap()));
+    /// ```
+    pub fn sort_key(&self) -> (u32, u128) {
+        (self.tag(), self.identity())
+    }
+}
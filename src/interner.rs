@@ -0,0 +1,72 @@
+use alloc::vec::Vec;
+use std::collections::HashMap;
+
+use crate::RawWindowHandle;
+
+/// A compact id for a handle interned in a [`HandleInterner`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct HandleId(u32);
+
+impl HandleId {
+    /// Returns the raw value of the id.
+    pub fn get(self) -> u32 {
+        self.0
+    }
+}
+
+/// A table mapping each distinct window handle to a small [`HandleId`].
+///
+/// Interning the same handle again returns the same id, and ids are stable for the lifetime of
+/// the interner: handles are never removed, so an id always resolves to the handle it was
+/// created for. Ids are only meaningful for the interner that created them.
+///
+/// ```
+/// # use windle::{HandleInterner, RawWindowHandle, Win32WindowHandle};
+/// let a = RawWindowHandle::from(Win32WindowHandle::from_hwnd(1).unwrap());
+/// let b = RawWindowHandle::from(Win32WindowHandle::from_hwnd(2).unwrap());
+///
+/// let mut interner = HandleInterner::new();
+/// let id = interner.intern(a);
+/// assert_eq!(interner.intern(a), id);
+/// assert_ne!(interner.intern(b), id);
+/// assert_eq!(interner.len(), 2);
+///
+/// assert_eq!(interner.resolve(id), Some(a));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct HandleInterner {
+    handles: Vec<RawWindowHandle>,
+    ids: HashMap<RawWindowHandle, HandleId>,
+}
+
+impl HandleInterner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the id of `handle`, assigning a new one if it wasn't interned yet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if more than `u32::MAX` distinct handles are interned.
+    pub fn intern(&mut self, handle: RawWindowHandle) -> HandleId {
+        *self.ids.entry(handle).or_insert_with(|| {
+            let id = u32::try_from(self.handles.len()).expect("too many interned handles");
+            self.handles.push(handle);
+            HandleId(id)
+        })
+    }
+
+    /// Returns the handle `id` was assigned to, or `None` if it doesn't come from this interner.
+    pub fn resolve(&self, id: HandleId) -> Option<RawWindowHandle> {
+        self.handles.get(id.0 as usize).copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.handles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.handles.is_empty()
+    }
+}
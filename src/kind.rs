@@ -0,0 +1,372 @@
+use core::ffi::CStr;
+
+use crate::{HandleError, RawDisplayHandle, RawWindowHandle};
+
+/// The kind of a [`RawWindowHandle`], without any of its fields.
+///
+/// [`RawWindowHandle`] is `#[non_exhaustive]`, so downstream `match`es need a catch-all arm. Use
+/// [`RawWindowHandleKind::ALL`] to assert at runtime that every current kind is handled.
+///
+/// ```
+/// # use windle::RawWindowHandleKind;
+/// fn describe(kind: RawWindowHandleKind) -> Option<&'static str> {
+///     match kind {
+///         RawWindowHandleKind::Win32 => Some("win32"),
+///         RawWindowHandleKind::WinRT => Some("winrt"),
+///         _ => None,
+///     }
+/// }
+///
+/// assert!(RawWindowHandleKind::ALL.iter().all(|&kind| describe(kind).is_some()));
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RawWindowHandleKind {
+    /// The kind of [`RawWindowHandle::Win32`].
+    Win32,
+    /// The kind of [`RawWindowHandle::WinRT`].
+    WinRT,
+}
+
+impl RawWindowHandleKind {
+    /// Every kind of [`RawWindowHandle`], in declaration order.
+    ///
+    /// This list grows as the crate gains new variants.
+    ///
+    /// ```
+    /// # use windle::{RawWindowHandle, RawWindowHandleKind, Win32WindowHandle, WinRTWindowHandle};
+    /// let handles = [
+    ///     RawWindowHandle::Win32(Win32WindowHandle::empty()),
+    ///     RawWindowHandle::WinRT(WinRTWindowHandle::empty()),
+    /// ];
+    /// assert_eq!(RawWindowHandleKind::ALL.len(), handles.len());
+    /// for (handle, kind) in handles.iter().zip(RawWindowHandleKind::ALL) {
+    ///     assert_eq!(handle.kind(), *kind);
+    /// }
+    /// ```
+    pub const ALL: &'static [RawWindowHandleKind] =
+        &[RawWindowHandleKind::Win32, RawWindowHandleKind::WinRT];
+
+    /// Returns the position of this kind in [`ALL`](Self::ALL).
+    ///
+    /// Indices are stable: new kinds are only ever appended, so the index can be used to look up
+    /// a `[T; RawWindowHandleKind::ALL.len()]` table.
+    ///
+    /// ```
+    /// # use windle::RawWindowHandleKind;
+    /// for &kind in RawWindowHandleKind::ALL {
+    ///     assert_eq!(RawWindowHandleKind::from_index(kind.index()), Some(kind));
+    /// }
+    /// assert_eq!(RawWindowHandleKind::from_index(RawWindowHandleKind::ALL.len()), None);
+    /// ```
+    pub const fn index(self) -> usize {
+        // Like `name()` and `RawWindowHandle::kind()`, this match has no catch-all arm on purpose:
+        // adding a variant must fail to compile until it is given an index here.
+        match self {
+            RawWindowHandleKind::Win32 => 0,
+            RawWindowHandleKind::WinRT => 1,
+        }
+    }
+
+    /// Returns the kind at `index` in [`ALL`](Self::ALL), if any.
+    pub fn from_index(index: usize) -> Option<Self> {
+        Self::ALL.get(index).copied()
+    }
+
+    /// Returns `true` if handles of this kind may only be used from the thread that owns them.
+    ///
+    /// | Kind                              | Thread-affine                           |
+    /// |-----------------------------------|-----------------------------------------|
+    /// | [`RawWindowHandleKind::Win32`]    | no                                      |
+    /// | [`RawWindowHandleKind::WinRT`]    | yes, the `CoreWindow`'s UI thread       |
+    ///
+    /// An `HWND` can be passed to and used by other threads (only some operations, like
+    /// destroying the window, are restricted to its owner), while a `CoreWindow` or
+    /// `SwapChainPanel` must be accessed from the UI thread it belongs to.
+    ///
+    /// ```
+    /// # use windle::RawWindowHandleKind;
+    /// assert!(!RawWindowHandleKind::Win32.is_thread_affine());
+    /// assert!(RawWindowHandleKind::WinRT.is_thread_affine());
+    /// ```
+    pub fn is_thread_affine(self) -> bool {
+        match self {
+            RawWindowHandleKind::Win32 => false,
+            RawWindowHandleKind::WinRT => true,
+        }
+    }
+
+    /// Returns the number of fields of this kind's handle struct.
+    ///
+    /// | Kind                           | Fields                                                |
+    /// |--------------------------------|-------------------------------------------------------|
+    /// | [`RawWindowHandleKind::Win32`] | 3: `hwnd`, `hinstance`, `composition_visual`          |
+    /// | [`RawWindowHandleKind::WinRT`] | 3: `core_window`, `swap_chain_panel`, `panel_index`   |
+    ///
+    /// This is also the number of meaningful slots in [`RawWindowHandle::into_parts`].
+    pub fn field_count(self) -> usize {
+        self.field_names().len()
+    }
+
+    /// Returns the names of the fields of this kind's handle struct, in declaration order.
+    pub(crate) fn field_names(self) -> &'static [&'static str] {
+        match self {
+            RawWindowHandleKind::Win32 => &["hwnd", "hinstance", "composition_visual"],
+            RawWindowHandleKind::WinRT => &["core_window", "swap_chain_panel", "panel_index"],
+        }
+    }
+
+    /// Returns the name of this kind, as used by the textual form of [`RawWindowHandle`].
+    pub fn name(self) -> &'static str {
+        // No catch-all arm: every kind needs a name for the textual form.
+        match self {
+            RawWindowHandleKind::Win32 => "Win32",
+            RawWindowHandleKind::WinRT => "WinRT",
+        }
+    }
+}
+
+/// An operating system family, as referred to by the "Availability Hints" of [`RawWindowHandle`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Target {
+    /// Microsoft Windows (`target_os = "windows"`).
+    Windows,
+    /// Linux, excluding Android (`target_os = "linux"`).
+    Linux,
+    /// Apple macOS (`target_os = "macos"`).
+    MacOS,
+    /// Apple iOS (`target_os = "ios"`).
+    Ios,
+    /// Android (`target_os = "android"`).
+    Android,
+    /// WebAssembly running in a browser (`wasm32-unknown-unknown`). WASI targets are not web
+    /// targets and have no windowing system.
+    Web,
+    /// Redox OS (`target_os = "redox"`).
+    Redox,
+}
+
+impl Target {
+    /// Returns the target this crate is compiled for, if it is one of the known targets.
+    ///
+    /// Only WebAssembly targets without an OS (`target_os = "unknown"`) are considered
+    /// [`Target::Web`]; WASI and other WebAssembly targets return `None`.
+    pub fn current() -> Option<Self> {
+        if cfg!(target_os = "windows") {
+            Some(Target::Windows)
+        } else if cfg!(target_os = "linux") {
+            Some(Target::Linux)
+        } else if cfg!(target_os = "macos") {
+            Some(Target::MacOS)
+        } else if cfg!(target_os = "ios") {
+            Some(Target::Ios)
+        } else if cfg!(target_os = "android") {
+            Some(Target::Android)
+        } else if cfg!(all(target_family = "wasm", target_os = "unknown")) {
+            Some(Target::Web)
+        } else if cfg!(target_os = "redox") {
+            Some(Target::Redox)
+        } else {
+            None
+        }
+    }
+}
+
+impl RawWindowHandleKind {
+    /// Returns whether this kind of handle is expected on `target`, following the "Availability
+    /// Hints" documented on each [`RawWindowHandle`] variant.
+    ///
+    /// This is advisory only: as the hints themselves, it is not normative, and an implementer is
+    /// allowed to return an unexpected handle. Tools can use it to emit warnings.
+    ///
+    /// ```
+    /// # use windle::{RawWindowHandleKind, Target};
+    /// assert!(RawWindowHandleKind::Win32.is_expected_on(Target::Windows));
+    /// assert!(RawWindowHandleKind::WinRT.is_expected_on(Target::Windows));
+    /// assert!(!RawWindowHandleKind::Win32.is_expected_on(Target::Linux));
+    /// assert!(!RawWindowHandleKind::WinRT.is_expected_on(Target::Web));
+    /// ```
+    pub fn is_expected_on(self, target: Target) -> bool {
+        match self {
+            RawWindowHandleKind::Win32 | RawWindowHandleKind::WinRT => target == Target::Windows,
+        }
+    }
+}
+
+impl RawWindowHandle {
+    /// Returns the [name](RawWindowHandleKind::name) of this handle's kind as a nul-terminated C
+    /// string, e.g. for an FFI diagnostics callback taking a `*const c_char`.
+    ///
+    /// The strings are static, so they never allocate and stay valid for the whole program. They
+    /// are as stable as the names themselves.
+    ///
+    /// ```
+    /// # use windle::{RawWindowHandle, Win32WindowHandle, WinRTWindowHandle};
+    /// for handle in [
+    ///     RawWindowHandle::Win32(Win32WindowHandle::empty()),
+    ///     RawWindowHandle::WinRT(WinRTWindowHandle::empty()),
+    /// ] {
+    ///     assert_eq!(handle.type_name_cstr().to_str(), Ok(handle.kind().name()));
+    /// }
+    /// ```
+    pub fn type_name_cstr(&self) -> &'static CStr {
+        match self.kind() {
+            RawWindowHandleKind::Win32 => c"Win32",
+            RawWindowHandleKind::WinRT => c"WinRT",
+        }
+    }
+
+    /// Returns the kind of this handle.
+    ///
+    /// ```
+    /// # use windle::{RawWindowHandle, RawWindowHandleKind, WinRTWindowHandle};
+    /// let handle = RawWindowHandle::WinRT(WinRTWindowHandle::empty());
+    /// assert_eq!(handle.kind(), RawWindowHandleKind::WinRT);
+    /// ```
+    pub fn kind(&self) -> RawWindowHandleKind {
+        // No catch-all arm: a new variant must be given a kind here, which in turn makes the
+        // matches in `RawWindowHandleKind` require a name and an index (and so a tag) for it.
+        match self {
+            RawWindowHandle::Win32(_) => RawWindowHandleKind::Win32,
+            RawWindowHandle::WinRT(_) => RawWindowHandleKind::WinRT,
+        }
+    }
+}
+
+/// The windowing system a window or display handle belongs to.
+///
+/// Window handles of several kinds can belong to the same windowing system: both
+/// [`RawWindowHandle::Win32`] and [`RawWindowHandle::WinRT`] are [`WindowingSystem::Windows`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WindowingSystem {
+    /// The Windows windowing system, of [`RawDisplayHandle::Windows`].
+    Windows,
+}
+
+impl RawWindowHandleKind {
+    /// Returns the windowing system handles of this kind belong to.
+    pub fn windowing_system(self) -> WindowingSystem {
+        match self {
+            RawWindowHandleKind::Win32 | RawWindowHandleKind::WinRT => WindowingSystem::Windows,
+        }
+    }
+}
+
+impl RawWindowHandle {
+    /// Returns the windowing system this handle belongs to.
+    pub fn windowing_system(&self) -> WindowingSystem {
+        self.kind().windowing_system()
+    }
+
+    /// Returns the windowing system of the display handle this window needs to create a surface,
+    /// or `None` if it doesn't need a separate display handle.
+    ///
+    /// | Variant                    | Required display |
+    /// |----------------------------|------------------|
+    /// | [`RawWindowHandle::Win32`] | none             |
+    /// | [`RawWindowHandle::WinRT`] | none             |
+    ///
+    /// A window that requires a display can only be used together with a [`RawDisplayHandle`]
+    /// whose [`windowing_system`](RawDisplayHandle::windowing_system) is the returned one, which
+    /// can be checked with [`RawDisplayHandle::require`].
+    ///
+    /// ```
+    /// # use windle::{RawWindowHandle, Win32WindowHandle, WinRTWindowHandle};
+    /// assert_eq!(RawWindowHandle::Win32(Win32WindowHandle::empty()).required_display_system(), None);
+    /// assert_eq!(RawWindowHandle::WinRT(WinRTWindowHandle::empty()).required_display_system(), None);
+    /// ```
+    pub fn required_display_system(&self) -> Option<WindowingSystem> {
+        match self {
+            RawWindowHandle::Win32(_) | RawWindowHandle::WinRT(_) => None,
+        }
+    }
+}
+
+impl RawDisplayHandle {
+    /// Returns the windowing system this handle belongs to.
+    pub fn windowing_system(&self) -> WindowingSystem {
+        match self {
+            RawDisplayHandle::Windows(_) => WindowingSystem::Windows,
+        }
+    }
+
+    /// Returns this handle if it belongs to `system`, or [`HandleError::NotSupported`] otherwise.
+    ///
+    /// This is meant as a guard clause for backends that only support one windowing system:
+    ///
+    /// ```
+    /// # use windle::{HandleError, RawDisplayHandle, WindowingSystem};
+    /// fn create_surface(display: &RawDisplayHandle) -> Result<(), HandleError> {
+    ///     let display = display.require(WindowingSystem::Windows)?;
+    ///     // ... use the Windows display ...
+    ///     # let _ = display;
+    ///     Ok(())
+    /// }
+    ///
+    /// assert_eq!(create_surface(&RawDisplayHandle::windows()), Ok(()));
+    /// ```
+    pub fn require(&self, system: WindowingSystem) -> Result<&RawDisplayHandle, HandleError> {
+        if self.windowing_system() == system {
+            Ok(self)
+        } else {
+            Err(HandleError::NotSupported)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Win32WindowHandle, WinRTWindowHandle};
+
+    #[test]
+    fn every_window_variant_has_a_kind_name_and_tag() {
+        let handles = [
+            RawWindowHandle::Win32(Win32WindowHandle::empty()),
+            RawWindowHandle::WinRT(WinRTWindowHandle::empty()),
+        ];
+        assert_eq!(handles.len(), RawWindowHandleKind::ALL.len());
+
+        for (handle, &kind) in handles.iter().zip(RawWindowHandleKind::ALL) {
+            assert_eq!(handle.kind(), kind);
+            assert_eq!(handle.tag(), kind.index() as u32);
+            let same_name = RawWindowHandleKind::ALL
+                .iter()
+                .filter(|other| other.name() == kind.name());
+            assert_eq!(same_name.count(), 1);
+        }
+    }
+
+    /// Destructures the empty handle of `$kind` without `..`, so adding a field to the struct
+    /// fails to compile here until `field_names()` (and so `field_count()`) is updated.
+    macro_rules! assert_fields {
+        ($kind: expr, $handle: ident { $($field: ident),* }) => {{
+            let $handle { $($field: _),* } = $handle::empty();
+            let names = [$(stringify!($field)),*];
+            assert_eq!($kind.field_names(), names);
+            assert_eq!($kind.field_count(), names.len());
+        }};
+    }
+
+    #[test]
+    fn field_names_match_the_structs() {
+        assert_fields!(
+            RawWindowHandleKind::Win32,
+            Win32WindowHandle {
+                hwnd,
+                hinstance,
+                composition_visual
+            }
+        );
+        assert_fields!(
+            RawWindowHandleKind::WinRT,
+            WinRTWindowHandle {
+                core_window,
+                swap_chain_panel,
+                panel_index
+            }
+        );
+    }
+}
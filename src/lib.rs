@@ -1,6 +1,73 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod arg;
+#[macro_use]
+mod bitset;
+mod cache;
+mod capabilities;
+#[cfg(feature = "alloc")]
+mod diff;
+mod dispatch;
+mod embedded;
+mod error;
+mod hint;
+mod identity;
+#[cfg(feature = "std")]
+mod interner;
+mod kind;
+mod mask;
+mod message;
+#[cfg(feature = "alloc")]
+mod owned;
+mod parse;
+#[cfg(feature = "test-util")]
+mod poison;
+mod redact;
+#[cfg(feature = "std")]
+mod registry;
+#[cfg(feature = "unsafe-union")]
+mod repr;
+mod scaled;
+#[cfg(feature = "std")]
+mod set;
+mod vulkan;
 mod windows;
 
-pub use windows::{Win32WindowHandle, WinRTWindowHandle, WindowsDisplayHandle};
+use core::ffi::c_void;
+use core::ptr;
+
+pub use arg::DisplayHandleArg;
+pub use cache::{CachedDisplayHandle, CachedWindowHandle, FrozenWindowHandle};
+pub use capabilities::HandleCapabilities;
+#[cfg(feature = "alloc")]
+pub use diff::HandleDiff;
+pub use embedded::EmbeddedWindowHandle;
+pub use error::HandleError;
+pub use hint::{ColorSpaceHint, HintedWindowHandle};
+#[cfg(feature = "std")]
+pub use interner::{HandleId, HandleInterner};
+pub use kind::{RawWindowHandleKind, Target, WindowingSystem};
+pub use mask::{filter_kinds, KindMask};
+pub use message::{ProtoWindowHandle, WindleHandleMessage};
+#[cfg(feature = "alloc")]
+pub use owned::OwnedDisplayHandle;
+pub use parse::HandleParseError;
+pub use redact::RedactedWindowHandle;
+#[cfg(feature = "std")]
+pub use registry::HandleRegistry;
+#[cfg(feature = "unsafe-union")]
+pub use repr::HandleUnion;
+pub use scaled::ScaledWindowHandle;
+#[cfg(feature = "std")]
+pub use set::WindowHandleSet;
+pub use vulkan::required_instance_extensions;
+pub use windows::{
+    Win32WindowHandle, Win32WindowHandleBuilder, WinRTWindowHandle, WinRTWindowHandleRef,
+    WindowsDisplayHandle,
+};
 
 /// Window that wraps around a raw window handle.
 ///
@@ -20,7 +87,7 @@ pub trait HasRawWindowHandle {
     fn raw_window_handle(&self) -> RawWindowHandle;
 }
 
-impl<'a, T: HasRawWindowHandle + ?Sized> HasRawWindowHandle for &'a T {
+impl<T: HasRawWindowHandle + ?Sized> HasRawWindowHandle for &T {
     fn raw_window_handle(&self) -> RawWindowHandle {
         (**self).raw_window_handle()
     }
@@ -46,7 +113,22 @@ impl<'a, T: HasRawWindowHandle + ?Sized> HasRawWindowHandle for &'a T {
 /// unexpected. (For example, it's legal for someone to return a
 /// [`RawWindowHandle::Xlib`] on macOS, it would just be weird, and probably
 /// requires something like XQuartz be used).
+///
+/// # Debug checks
+///
+/// With the `debug-checks` feature, converting a handle struct into a `RawWindowHandle` with
+/// [`From`] panics if the struct [is null](Self::is_null), so obviously invalid handles are caught
+/// where they are created. The check is a `debug_assert!`: it is compiled out in release builds,
+/// where the behavior is unchanged. Variants created directly (`RawWindowHandle::Win32(..)`) are
+/// not checked.
+///
+/// # Layout
+///
+/// The enum is `#[repr(C, u32)]`: a `u32` discriminant equal to the [tag](Self::tag) of the
+/// variant, followed by a `#[repr(C)]` union of the handle structs. With the `unsafe-union`
+/// feature, this union can be viewed directly through `as_union`.
 #[non_exhaustive]
+#[repr(C, u32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum RawWindowHandle {
     /// A raw window handle for Win32.
@@ -61,6 +143,158 @@ pub enum RawWindowHandle {
     WinRT(WinRTWindowHandle),
 }
 
+impl RawWindowHandle {
+    /// The number of variants of [`RawWindowHandle`].
+    ///
+    /// This is the canonical count and grows with the crate. It is the length of
+    /// [`RawWindowHandleKind::ALL`], which lists one kind per variant.
+    pub const VARIANT_COUNT: usize = RawWindowHandleKind::ALL.len();
+
+    /// Returns `true` if the handle doesn't refer to a window, i.e. its mandatory fields are null.
+    ///
+    /// ```
+    /// # use windle::{RawWindowHandle, Win32WindowHandle};
+    /// assert!(RawWindowHandle::Win32(Win32WindowHandle::empty()).is_null());
+    /// assert!(!RawWindowHandle::from(Win32WindowHandle::from_hwnd(1).unwrap()).is_null());
+    /// ```
+    pub fn is_null(&self) -> bool {
+        match self {
+            RawWindowHandle::Win32(handle) => handle.is_null(),
+            RawWindowHandle::WinRT(handle) => handle.is_null(),
+        }
+    }
+
+    /// Checks that the fields of the handle make sense together.
+    ///
+    /// The rules are documented on each handle struct, see
+    /// [`Win32WindowHandle::check_consistency`] and [`WinRTWindowHandle::check_consistency`].
+    pub fn check_consistency(&self) -> Result<(), HandleError> {
+        match self {
+            RawWindowHandle::Win32(handle) => handle.check_consistency(),
+            RawWindowHandle::WinRT(handle) => handle.check_consistency(),
+        }
+    }
+
+    /// Replaces the `HWND` of a [`RawWindowHandle::Win32`] handle in place.
+    ///
+    /// This lets long-lived structs swap the underlying window after it was recreated without
+    /// rebuilding the enum.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HandleError::NotSupported`] if the handle is not a [`RawWindowHandle::Win32`].
+    ///
+    /// ```
+    /// # use windle::{HandleError, RawWindowHandle, Win32WindowHandle, WinRTWindowHandle};
+    /// # let hwnd = core::ptr::without_provenance_mut::<core::ffi::c_void>(0x1000);
+    /// let mut handle = RawWindowHandle::Win32(Win32WindowHandle::empty());
+    /// handle.set_hwnd(hwnd).unwrap();
+    /// assert!(matches!(handle, RawWindowHandle::Win32(h) if h.hwnd == hwnd));
+    ///
+    /// let mut handle = RawWindowHandle::WinRT(WinRTWindowHandle::empty());
+    /// assert_eq!(handle.set_hwnd(hwnd), Err(HandleError::NotSupported));
+    /// ```
+    pub fn set_hwnd(&mut self, hwnd: *mut c_void) -> Result<(), HandleError> {
+        match self {
+            RawWindowHandle::Win32(handle) => {
+                handle.set_hwnd(hwnd);
+                Ok(())
+            }
+            _ => Err(HandleError::NotSupported),
+        }
+    }
+
+    /// Checks the handle against the running OS where such a check exists.
+    ///
+    /// This performs real syscalls and is only available with the opt-in `validate-os` feature.
+    /// The checks performed per variant are:
+    ///
+    /// - [`RawWindowHandle::Win32`]: `Win32WindowHandle::is_window_alive`, on Windows only.
+    ///
+    /// Variants (or targets) without a check return `Ok(())`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HandleError::Unavailable`] if the OS reports that the handle is not valid.
+    #[cfg(feature = "validate-os")]
+    pub fn validate_os(&self) -> Result<(), HandleError> {
+        match self {
+            #[cfg(windows)]
+            RawWindowHandle::Win32(handle) if !handle.is_window_alive() => {
+                Err(HandleError::Unavailable)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns a copy of this handle with every non-null native pointer passed through `f`.
+    ///
+    /// Null pointers and non-pointer fields are left untouched. The visited fields are:
+    ///
+    /// | Variant                    | Fields                                        |
+    /// |----------------------------|-----------------------------------------------|
+    /// | [`RawWindowHandle::Win32`] | `hwnd`, `hinstance`, `composition_visual`     |
+    /// | [`RawWindowHandle::WinRT`] | `core_window`, `swap_chain_panel`             |
+    ///
+    /// ```
+    /// # use windle::{RawWindowHandle, Win32WindowHandle};
+    /// let mut window_handle = Win32WindowHandle::from_hwnd(0x1000).unwrap();
+    /// let handle = RawWindowHandle::from(window_handle);
+    ///
+    /// let remapped = handle.map_pointers(|ptr| ptr.wrapping_byte_add(0x10));
+    ///
+    /// window_handle.hwnd = window_handle.hwnd.wrapping_byte_add(0x10);
+    /// assert_eq!(remapped, RawWindowHandle::from(window_handle));
+    /// ```
+    pub fn map_pointers(&self, f: impl Fn(*mut c_void) -> *mut c_void) -> RawWindowHandle {
+        let map = |ptr: *mut c_void| if ptr.is_null() { ptr } else { f(ptr) };
+
+        match *self {
+            RawWindowHandle::Win32(mut handle) => {
+                handle.hwnd = map(handle.hwnd);
+                handle.hinstance = map(handle.hinstance);
+                handle.composition_visual = map(handle.composition_visual);
+                RawWindowHandle::Win32(handle)
+            }
+            RawWindowHandle::WinRT(mut handle) => {
+                handle.core_window = map(handle.core_window);
+                handle.swap_chain_panel = map(handle.swap_chain_panel);
+                RawWindowHandle::WinRT(handle)
+            }
+        }
+    }
+
+    /// Iterates over the non-null native pointers of this handle.
+    ///
+    /// The yielded fields are the same as the ones visited by
+    /// [`map_pointers`](Self::map_pointers), in the order listed there.
+    ///
+    /// ```
+    /// # use windle::{RawWindowHandle, Win32WindowHandle};
+    /// # let ptr = |addr: usize| core::ptr::without_provenance_mut::<core::ffi::c_void>(addr);
+    /// let mut window_handle = Win32WindowHandle::empty();
+    /// window_handle.hwnd = ptr(0x1000);
+    /// window_handle.hinstance = ptr(0x2000);
+    /// let handle = RawWindowHandle::from(window_handle);
+    /// assert_eq!(handle.pointers().collect::<Vec<_>>(), [ptr(0x1000), ptr(0x2000)]);
+    ///
+    /// window_handle.hinstance = core::ptr::null_mut();
+    /// let handle = RawWindowHandle::from(window_handle);
+    /// assert_eq!(handle.pointers().collect::<Vec<_>>(), [ptr(0x1000)]);
+    /// ```
+    pub fn pointers(&self) -> impl Iterator<Item = *mut c_void> {
+        let pointers = match *self {
+            RawWindowHandle::Win32(handle) => {
+                [handle.hwnd, handle.hinstance, handle.composition_visual]
+            }
+            RawWindowHandle::WinRT(handle) => {
+                [handle.core_window, handle.swap_chain_panel, ptr::null_mut()]
+            }
+        };
+        pointers.into_iter().filter(|ptr| !ptr.is_null())
+    }
+}
+
 /// Display that wraps around a raw display handle.
 ///
 /// # Safety
@@ -79,12 +313,137 @@ pub trait HasRawDisplayHandle {
     fn raw_display_handle(&self) -> RawDisplayHandle;
 }
 
-impl<'a, T: HasRawDisplayHandle + ?Sized> HasRawDisplayHandle for &'a T {
+impl<T: HasRawDisplayHandle + ?Sized> HasRawDisplayHandle for &T {
     fn raw_display_handle(&self) -> RawDisplayHandle {
         (*self).raw_display_handle()
     }
 }
 
+/// Placeholder handles for `()`, so it can stand in for a window in generic tests.
+///
+/// These return a null [`RawWindowHandle::Win32`] and an empty [`RawDisplayHandle::Windows`].
+/// They are intentionally invalid: the window handle [is null](RawWindowHandle::is_null) and must
+/// not be passed to any API expecting a real window. They never panic, even with the
+/// `debug-checks` feature.
+///
+/// ```
+/// # use windle::*;
+/// fn handles(window: impl HasRawWindowHandle + HasRawDisplayHandle) -> RawHandlePair {
+///     RawHandlePair::new(window.raw_window_handle(), window.raw_display_handle())
+/// }
+///
+/// let pair = handles(());
+/// assert!(pair.window.is_null());
+/// assert_eq!(pair.display, RawDisplayHandle::windows());
+/// ```
+impl HasRawWindowHandle for () {
+    fn raw_window_handle(&self) -> RawWindowHandle {
+        RawWindowHandle::Win32(Win32WindowHandle::empty())
+    }
+}
+
+/// See the [`HasRawWindowHandle`] implementation for `()`.
+impl HasRawDisplayHandle for () {
+    fn raw_display_handle(&self) -> RawDisplayHandle {
+        RawDisplayHandle::Windows(WindowsDisplayHandle::empty())
+    }
+}
+
+/// An object providing both a window handle and the display handle it belongs to.
+///
+/// This is implemented for every type implementing both [`HasRawWindowHandle`] and
+/// [`HasRawDisplayHandle`], so an API can take a single `&dyn HasRawHandles` and be sure both
+/// handles come from the same object.
+///
+/// ```
+/// # use windle::*;
+/// struct MockWindow;
+///
+/// impl HasRawWindowHandle for MockWindow {
+///     fn raw_window_handle(&self) -> RawWindowHandle {
+///         RawWindowHandle::Win32(Win32WindowHandle::empty())
+///     }
+/// }
+///
+/// impl HasRawDisplayHandle for MockWindow {
+///     fn raw_display_handle(&self) -> RawDisplayHandle {
+///         WindowsDisplayHandle::empty().into()
+///     }
+/// }
+///
+/// fn handles(window: &dyn HasRawHandles) -> (RawWindowHandle, RawDisplayHandle) {
+///     (
+///         window.as_window().raw_window_handle(),
+///         window.as_display().raw_display_handle(),
+///     )
+/// }
+///
+/// assert_eq!(
+///     handles(&MockWindow),
+///     (RawWindowHandle::Win32(Win32WindowHandle::empty()), WindowsDisplayHandle::empty().into()),
+/// );
+/// ```
+pub trait HasRawHandles {
+    fn as_window(&self) -> &dyn HasRawWindowHandle;
+    fn as_display(&self) -> &dyn HasRawDisplayHandle;
+}
+
+impl<T: HasRawWindowHandle + HasRawDisplayHandle> HasRawHandles for T {
+    fn as_window(&self) -> &dyn HasRawWindowHandle {
+        self
+    }
+
+    fn as_display(&self) -> &dyn HasRawDisplayHandle {
+        self
+    }
+}
+
+/// An object-safe provider of both handles, for storing heterogeneous windows together.
+///
+/// Unlike [`HasRawWindowHandle`] and [`HasRawDisplayHandle`], both methods are fallible, so a
+/// provider can report a handle that is not currently available. The trait only has `&self`
+/// methods without generics, so it can be used as `dyn HandleProvider`.
+///
+/// It is implemented for every type implementing both [`HasRawWindowHandle`] and
+/// [`HasRawDisplayHandle`], whose handles are always available.
+///
+/// ```
+/// # use windle::*;
+/// struct MockWindow(isize);
+///
+/// impl HasRawWindowHandle for MockWindow {
+///     fn raw_window_handle(&self) -> RawWindowHandle {
+///         Win32WindowHandle::from_hwnd(self.0).unwrap().into()
+///     }
+/// }
+///
+/// impl HasRawDisplayHandle for MockWindow {
+///     fn raw_display_handle(&self) -> RawDisplayHandle {
+///         RawDisplayHandle::windows()
+///     }
+/// }
+///
+/// let windows: Vec<Box<dyn HandleProvider>> = vec![Box::new(MockWindow(1)), Box::new(MockWindow(2))];
+/// for window in &windows {
+///     assert!(!window.window().unwrap().is_null());
+///     assert_eq!(window.display(), Ok(RawDisplayHandle::windows()));
+/// }
+/// ```
+pub trait HandleProvider {
+    fn window(&self) -> Result<RawWindowHandle, HandleError>;
+    fn display(&self) -> Result<RawDisplayHandle, HandleError>;
+}
+
+impl<T: HasRawWindowHandle + HasRawDisplayHandle + ?Sized> HandleProvider for T {
+    fn window(&self) -> Result<RawWindowHandle, HandleError> {
+        Ok(self.raw_window_handle())
+    }
+
+    fn display(&self) -> Result<RawDisplayHandle, HandleError> {
+        Ok(self.raw_display_handle())
+    }
+}
+
 /// A display server handle for a particular windowing system.
 ///
 /// The display usually represents a connection to some display server, but it is not necessarily
@@ -117,6 +476,113 @@ pub enum RawDisplayHandle {
     Windows(WindowsDisplayHandle),
 }
 
+impl RawDisplayHandle {
+    /// The number of variants of [`RawDisplayHandle`].
+    ///
+    /// This is the canonical count and grows with the crate.
+    pub const VARIANT_COUNT: usize = 1;
+
+    /// Returns the canonical, empty Windows display handle.
+    ///
+    /// ```
+    /// # use windle::{RawDisplayHandle, WindowsDisplayHandle};
+    /// assert_eq!(
+    ///     RawDisplayHandle::windows(),
+    ///     RawDisplayHandle::Windows(WindowsDisplayHandle::empty()),
+    /// );
+    /// ```
+    pub fn windows() -> Self {
+        RawDisplayHandle::Windows(WindowsDisplayHandle::default())
+    }
+
+    /// Returns a window handle of the windowing system this display belongs to, with all its fields
+    /// null.
+    ///
+    /// Headless tools can use this to later open a window on the same system. The pairing is:
+    ///
+    /// | Display                       | Window                                   |
+    /// |-------------------------------|------------------------------------------|
+    /// | [`RawDisplayHandle::Windows`] | [`RawWindowHandle::Win32`] (null `HWND`) |
+    ///
+    /// Displays without a corresponding window handle return `None`.
+    ///
+    /// ```
+    /// # use windle::{RawDisplayHandle, RawWindowHandle, Win32WindowHandle};
+    /// assert_eq!(
+    ///     RawDisplayHandle::windows().default_window_handle(),
+    ///     Some(RawWindowHandle::Win32(Win32WindowHandle::empty())),
+    /// );
+    /// ```
+    pub fn default_window_handle(&self) -> Option<RawWindowHandle> {
+        match self {
+            RawDisplayHandle::Windows(_) => {
+                Some(RawWindowHandle::Win32(Win32WindowHandle::empty()))
+            }
+        }
+    }
+}
+
+/// A window handle together with the display handle it belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RawHandlePair {
+    /// The window handle.
+    pub window: RawWindowHandle,
+    /// The display handle of the display server the window belongs to.
+    pub display: RawDisplayHandle,
+}
+
+impl RawHandlePair {
+    pub fn new(window: RawWindowHandle, display: RawDisplayHandle) -> Self {
+        Self { window, display }
+    }
+
+    /// Returns an adapter providing [`window`](Self::window) through [`HasRawWindowHandle`].
+    ///
+    /// The adapter borrows the pair, so it can't outlive it.
+    ///
+    /// ```
+    /// # use windle::*;
+    /// fn window_of(window: impl HasRawWindowHandle) -> RawWindowHandle {
+    ///     window.raw_window_handle()
+    /// }
+    ///
+    /// fn display_of(display: impl HasRawDisplayHandle) -> RawDisplayHandle {
+    ///     display.raw_display_handle()
+    /// }
+    ///
+    /// let window = RawWindowHandle::from(Win32WindowHandle::from_hwnd(0x1000).unwrap());
+    /// let pair = RawHandlePair::new(window, RawDisplayHandle::windows());
+    /// assert_eq!(window_of(pair.as_window_provider()), pair.window);
+    /// assert_eq!(display_of(pair.as_display_provider()), pair.display);
+    /// ```
+    pub fn as_window_provider(&self) -> impl HasRawWindowHandle + '_ {
+        WindowProvider(self)
+    }
+
+    /// Returns an adapter providing [`display`](Self::display) through [`HasRawDisplayHandle`].
+    ///
+    /// As [`as_window_provider`](Self::as_window_provider), the adapter borrows the pair.
+    pub fn as_display_provider(&self) -> impl HasRawDisplayHandle + '_ {
+        DisplayProvider(self)
+    }
+}
+
+struct WindowProvider<'a>(&'a RawHandlePair);
+
+impl HasRawWindowHandle for WindowProvider<'_> {
+    fn raw_window_handle(&self) -> RawWindowHandle {
+        self.0.window
+    }
+}
+
+struct DisplayProvider<'a>(&'a RawHandlePair);
+
+impl HasRawDisplayHandle for DisplayProvider<'_> {
+    fn raw_display_handle(&self) -> RawDisplayHandle {
+        self.0.display
+    }
+}
+
 macro_rules! from_impl {
     ($($to: ident, $enum: ident, $from: ty)*) => ($(
         impl From<$from> for $to {
@@ -129,5 +595,124 @@ macro_rules! from_impl {
 
 from_impl!(RawDisplayHandle, Windows, WindowsDisplayHandle);
 
-from_impl!(RawWindowHandle, Win32, Win32WindowHandle);
-from_impl!(RawWindowHandle, WinRT, WinRTWindowHandle);
+/// Like `from_impl!`, with the `debug-checks` assertion that the handle is not null.
+macro_rules! checked_from_impl {
+    ($($to: ident, $enum: ident, $from: ty)*) => ($(
+        impl From<$from> for $to {
+            fn from(value: $from) -> Self {
+                #[cfg(feature = "debug-checks")]
+                debug_assert!(
+                    !value.is_null(),
+                    concat!("invalid `", stringify!($from), "`: {}"),
+                    HandleError::Unavailable,
+                );
+                $to::$enum(value)
+            }
+        }
+    )*)
+}
+
+checked_from_impl!(RawWindowHandle, Win32, Win32WindowHandle);
+checked_from_impl!(RawWindowHandle, WinRT, WinRTWindowHandle);
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A handle struct wrapped by one of the variants of [`RawWindowHandle`] or [`RawDisplayHandle`].
+///
+/// This trait is sealed: it is implemented for every handle struct of this crate and cannot be
+/// implemented outside of it. It backs [`RawWindowHandle::get`] and [`RawDisplayHandle::get`].
+pub trait ConcreteHandle: sealed::Sealed + Copy {
+    /// The enum wrapping this handle struct.
+    type Raw;
+
+    /// Returns the wrapped handle struct if `raw` is of the matching variant.
+    fn from_raw(raw: &Self::Raw) -> Option<Self>;
+}
+
+macro_rules! concrete_impl {
+    ($($to: ident, $enum: ident, $from: ty)*) => ($(
+        impl sealed::Sealed for $from {}
+
+        impl ConcreteHandle for $from {
+            type Raw = $to;
+
+            fn from_raw(raw: &$to) -> Option<Self> {
+                #[allow(unreachable_patterns)]
+                match raw {
+                    $to::$enum(handle) => Some(*handle),
+                    _ => None,
+                }
+            }
+        }
+    )*)
+}
+
+concrete_impl!(RawDisplayHandle, Windows, WindowsDisplayHandle);
+
+concrete_impl!(RawWindowHandle, Win32, Win32WindowHandle);
+concrete_impl!(RawWindowHandle, WinRT, WinRTWindowHandle);
+
+impl RawWindowHandle {
+    /// Returns the wrapped handle struct if it is a `T`.
+    ///
+    /// ```
+    /// # use windle::{RawWindowHandle, Win32WindowHandle, WinRTWindowHandle};
+    /// let window_handle = Win32WindowHandle::from_hwnd(1).unwrap();
+    /// let handle = RawWindowHandle::from(window_handle);
+    /// assert_eq!(handle.get::<Win32WindowHandle>(), Some(window_handle));
+    /// assert_eq!(handle.get::<WinRTWindowHandle>(), None);
+    /// ```
+    pub fn get<T: ConcreteHandle<Raw = Self>>(&self) -> Option<T> {
+        T::from_raw(self)
+    }
+}
+
+impl RawDisplayHandle {
+    /// Returns the wrapped handle struct if it is a `T`.
+    pub fn get<T: ConcreteHandle<Raw = Self>>(&self) -> Option<T> {
+        T::from_raw(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(debug_assertions, feature = "debug-checks"))]
+    #[test]
+    #[should_panic(expected = "invalid `Win32WindowHandle`")]
+    fn debug_checks_reject_null_win32() {
+        let _ = RawWindowHandle::from(Win32WindowHandle::empty());
+    }
+
+    #[test]
+    fn window_variant_count() {
+        // Every arm returns the number of variants. There is no wildcard arm, so adding a variant
+        // fails to compile until an arm is added here with the updated count.
+        fn variant_count(handle: RawWindowHandle) -> usize {
+            match handle {
+                RawWindowHandle::Win32(_) => 2,
+                RawWindowHandle::WinRT(_) => 2,
+            }
+        }
+
+        let count = variant_count(RawWindowHandle::Win32(Win32WindowHandle::empty()));
+        assert_eq!(RawWindowHandle::VARIANT_COUNT, count);
+        assert_eq!(RawWindowHandleKind::ALL.len(), count);
+    }
+
+    #[test]
+    fn display_variant_count() {
+        // As in `window_variant_count`.
+        fn variant_count(handle: RawDisplayHandle) -> usize {
+            match handle {
+                RawDisplayHandle::Windows(_) => 1,
+            }
+        }
+
+        let count = variant_count(RawDisplayHandle::Windows(WindowsDisplayHandle::empty()));
+        assert_eq!(RawDisplayHandle::VARIANT_COUNT, count);
+    }
+}
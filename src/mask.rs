@@ -0,0 +1,78 @@
+use crate::{RawWindowHandle, RawWindowHandleKind};
+
+/// A set of [`RawWindowHandleKind`]s, for filtering collections of handles.
+///
+/// Each kind has its own bit, at its [index](RawWindowHandleKind::index). Masks are combined with
+/// `|` and tested with [`contains`](Self::contains).
+///
+/// | Kind                           | Classification  |
+/// |--------------------------------|-----------------|
+/// | [`RawWindowHandleKind::Win32`] | pointer-based   |
+/// | [`RawWindowHandleKind::WinRT`] | pointer-based   |
+///
+/// Pointer-based handles identify their window with a native pointer; id-based handles (none so
+/// far) with an integer id.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct KindMask(u32);
+
+impl KindMask {
+    /// [`RawWindowHandleKind::Win32`].
+    pub const WIN32: Self = Self::from_kind(RawWindowHandleKind::Win32);
+    /// [`RawWindowHandleKind::WinRT`].
+    pub const WINRT: Self = Self::from_kind(RawWindowHandleKind::WinRT);
+    /// Every kind identifying its window with a native pointer.
+    pub const POINTER_BASED: Self = Self(Self::WIN32.0 | Self::WINRT.0);
+    /// Every kind identifying its window with an integer id.
+    ///
+    /// Empty: no current kind is id-based.
+    pub const ID_BASED: Self = Self::empty();
+
+    /// Returns the mask containing only `kind`.
+    pub const fn from_kind(kind: RawWindowHandleKind) -> Self {
+        Self(1 << kind.index())
+    }
+}
+
+bitset!(
+    KindMask,
+    RawWindowHandleKind::ALL
+        .iter()
+        .map(|&kind| (KindMask::from_kind(kind), kind.name()))
+);
+
+impl From<RawWindowHandleKind> for KindMask {
+    fn from(kind: RawWindowHandleKind) -> Self {
+        Self::from_kind(kind)
+    }
+}
+
+impl RawWindowHandle {
+    /// Returns `true` if the kind of this handle is in `mask`.
+    pub fn matches_mask(&self, mask: KindMask) -> bool {
+        mask.contains(self.kind().into())
+    }
+}
+
+/// Returns an iterator over the handles of `handles` whose kind is in `mask`.
+///
+/// ```
+/// # use windle::{filter_kinds, KindMask, RawWindowHandle, Win32WindowHandle, WinRTWindowHandle};
+/// let handles = vec![
+///     RawWindowHandle::Win32(Win32WindowHandle::empty()),
+///     RawWindowHandle::WinRT(WinRTWindowHandle::empty()),
+///     RawWindowHandle::Win32(Win32WindowHandle::empty()),
+/// ];
+///
+/// assert_eq!(filter_kinds(handles.iter().copied(), KindMask::WIN32).count(), 2);
+/// assert_eq!(filter_kinds(handles.iter().copied(), KindMask::WINRT).count(), 1);
+/// assert_eq!(filter_kinds(handles.iter().copied(), KindMask::POINTER_BASED).count(), 3);
+/// assert_eq!(filter_kinds(handles, KindMask::ID_BASED).count(), 0);
+/// ```
+pub fn filter_kinds<I>(handles: I, mask: KindMask) -> impl Iterator<Item = RawWindowHandle>
+where
+    I: IntoIterator<Item = RawWindowHandle>,
+{
+    handles
+        .into_iter()
+        .filter(move |handle| handle.matches_mask(mask))
+}
@@ -0,0 +1,239 @@
+use core::ffi::c_void;
+use core::ptr;
+
+use crate::{
+    HandleError, RawWindowHandle, RawWindowHandleKind, Win32WindowHandle, WinRTWindowHandle,
+};
+
+/// A fixed-size, `#[repr(C)]` encoding of a [`RawWindowHandle`], e.g. for a shared-memory mailbox.
+///
+/// [`kind`](Self::kind) is the [index](RawWindowHandleKind::index) of the handle's kind and the
+/// fields of the handle are packed into the slots as follows; unused slots are `0`.
+///
+/// | Variant                    | `a`           | `b`                | `c`                  |
+/// |----------------------------|---------------|--------------------|----------------------|
+/// | [`RawWindowHandle::Win32`] | `hwnd`        | `hinstance`        | `composition_visual` |
+/// | [`RawWindowHandle::WinRT`] | `core_window` | `swap_chain_panel` | `panel_index`        |
+///
+/// Pointers are stored as their address (see `<*const T>::expose_provenance`).
+/// The struct has no padding, so it is `Pod`-eligible.
+///
+/// ```
+/// # use windle::{RawWindowHandle, Win32WindowHandle, WinRTWindowHandle, WindleHandleMessage};
+/// # let ptr = |addr: usize| core::ptr::without_provenance_mut::<core::ffi::c_void>(addr);
+/// let mut win32 = Win32WindowHandle::empty();
+/// win32.hwnd = ptr(0x1000);
+/// win32.hinstance = ptr(0x2000);
+/// win32.composition_visual = ptr(0x5000);
+/// let winrt = WinRTWindowHandle::empty()
+///     .with_core_window(ptr(0x3000))
+///     .with_swap_chain_panel(ptr(0x4000))
+///     .with_panel_index(2);
+///
+/// for handle in [RawWindowHandle::from(win32), RawWindowHandle::from(winrt)] {
+///     let message = WindleHandleMessage::from(handle);
+///     assert_eq!(RawWindowHandle::try_from(message), Ok(handle));
+/// }
+///
+/// fn assert_copy<T: Copy>() {}
+/// assert_copy::<WindleHandleMessage>();
+/// assert_eq!(core::mem::size_of::<WindleHandleMessage>(), 2 * 4 + 3 * 8);
+/// ```
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WindleHandleMessage {
+    /// The [tag](RawWindowHandle::tag) of the handle's kind.
+    pub kind: u32,
+    /// Always `0`; makes the padding before the slots explicit.
+    pub reserved: u32,
+    /// The first field slot.
+    pub a: u64,
+    /// The second field slot.
+    pub b: u64,
+    /// The third field slot.
+    pub c: u64,
+}
+
+impl From<RawWindowHandle> for WindleHandleMessage {
+    fn from(handle: RawWindowHandle) -> Self {
+        let [a, b, c] = match handle {
+            RawWindowHandle::Win32(handle) => [
+                address(handle.hwnd),
+                address(handle.hinstance),
+                address(handle.composition_visual),
+            ],
+            RawWindowHandle::WinRT(handle) => [
+                address(handle.core_window),
+                address(handle.swap_chain_panel),
+                handle.panel_index.into(),
+            ],
+        };
+
+        Self {
+            kind: handle.tag(),
+            reserved: 0,
+            a,
+            b,
+            c,
+        }
+    }
+}
+
+impl TryFrom<WindleHandleMessage> for RawWindowHandle {
+    type Error = HandleError;
+
+    /// Rebuilds the handle, failing with [`HandleError::NotSupported`] on an unknown kind and with
+    /// [`HandleError::InvalidField`] if a slot doesn't fit its field.
+    fn try_from(message: WindleHandleMessage) -> Result<Self, Self::Error> {
+        let kind = RawWindowHandleKind::from_index(message.kind as usize)
+            .ok_or(HandleError::NotSupported)?;
+
+        let names = kind.field_names();
+        let fields = (pointer(message.a, names[0])?, pointer(message.b, names[1])?);
+        Ok(match kind {
+            RawWindowHandleKind::Win32 => {
+                let mut handle = Win32WindowHandle::from(fields);
+                handle.composition_visual = pointer(message.c, names[2])?;
+                RawWindowHandle::Win32(handle)
+            }
+            RawWindowHandleKind::WinRT => {
+                let panel_index =
+                    u32::try_from(message.c).map_err(|_| HandleError::InvalidField(names[2]))?;
+                RawWindowHandle::WinRT(
+                    WinRTWindowHandle::from(fields).with_panel_index(panel_index),
+                )
+            }
+        })
+    }
+}
+
+/// A scalar-only representation of a [`RawWindowHandle`], matching a protobuf message like:
+///
+/// ```proto
+/// message WindowHandle {
+///   uint32 kind = 1;
+///   uint64 field0 = 2;
+///   uint64 field1 = 3;
+///   uint64 field2 = 4;
+/// }
+/// ```
+///
+/// [`kind`](Self::kind) is the [tag](RawWindowHandle::tag) of the handle and the fields hold the
+/// same values as the slots of [`WindleHandleMessage`]: `field0` is `a`, `field1` is `b` and
+/// `field2` is `c`. Pointers are stored as their address.
+///
+/// ```
+/// # use windle::{HandleError, ProtoWindowHandle, RawWindowHandle, Win32WindowHandle, WinRTWindowHandle};
+/// # let ptr = |addr: usize| core::ptr::without_provenance_mut::<core::ffi::c_void>(addr);
+/// let win32 = Win32WindowHandle::from((ptr(0x1000), ptr(0x2000)));
+/// let winrt = WinRTWindowHandle::empty().with_swap_chain_panel(ptr(0x3000)).with_panel_index(1);
+///
+/// for handle in [RawWindowHandle::from(win32), RawWindowHandle::from(winrt)] {
+///     let proto = ProtoWindowHandle::from(handle);
+///     assert_eq!(RawWindowHandle::try_from(proto), Ok(handle));
+/// }
+///
+/// let unknown = ProtoWindowHandle { kind: u32::MAX, ..Default::default() };
+/// assert_eq!(RawWindowHandle::try_from(unknown), Err(HandleError::NotSupported));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ProtoWindowHandle {
+    /// The [tag](RawWindowHandle::tag) of the handle's kind.
+    pub kind: u32,
+    /// The first field, as [`WindleHandleMessage::a`].
+    pub field0: u64,
+    /// The second field, as [`WindleHandleMessage::b`].
+    pub field1: u64,
+    /// The third field, as [`WindleHandleMessage::c`].
+    pub field2: u64,
+}
+
+impl From<RawWindowHandle> for ProtoWindowHandle {
+    fn from(handle: RawWindowHandle) -> Self {
+        let message = WindleHandleMessage::from(handle);
+        Self {
+            kind: message.kind,
+            field0: message.a,
+            field1: message.b,
+            field2: message.c,
+        }
+    }
+}
+
+impl TryFrom<ProtoWindowHandle> for RawWindowHandle {
+    type Error = HandleError;
+
+    /// Rebuilds the handle, failing as the conversion from [`WindleHandleMessage`] does.
+    fn try_from(proto: ProtoWindowHandle) -> Result<Self, Self::Error> {
+        RawWindowHandle::try_from(WindleHandleMessage {
+            kind: proto.kind,
+            reserved: 0,
+            a: proto.field0,
+            b: proto.field1,
+            c: proto.field2,
+        })
+    }
+}
+
+impl RawWindowHandle {
+    /// Returns the tag identifying this handle's kind in FFI representations.
+    ///
+    /// This is the [index](RawWindowHandleKind::index) of the handle's kind, and the value of
+    /// [`WindleHandleMessage::kind`].
+    pub fn tag(&self) -> u32 {
+        // Derived from the exhaustive matches of `kind()` and `index()`, so every variant has one.
+        self.kind().index() as u32
+    }
+
+    /// Splits the handle into its [tag](Self::tag) and up to three field slots, e.g. to pass them
+    /// to C as separate out-parameters.
+    ///
+    /// The slots are laid out as in [`WindleHandleMessage`]. Reassemble the handle with
+    /// [`from_parts`](Self::from_parts).
+    ///
+    /// ```
+    /// # use windle::{RawWindowHandle, Win32WindowHandle, WinRTWindowHandle};
+    /// # let ptr = |addr: usize| core::ptr::without_provenance_mut::<core::ffi::c_void>(addr);
+    /// let win32 = Win32WindowHandle::from((ptr(0x1000), ptr(0x2000)));
+    /// let winrt = WinRTWindowHandle::from((ptr(0x3000), ptr(0x4000))).with_panel_index(1);
+    ///
+    /// for handle in [RawWindowHandle::from(win32), RawWindowHandle::from(winrt)] {
+    ///     let (tag, fields) = handle.into_parts();
+    ///     assert_eq!(tag, handle.tag());
+    ///     assert_eq!(RawWindowHandle::from_parts(tag, fields), Some(handle));
+    /// }
+    ///
+    /// assert_eq!(RawWindowHandle::from_parts(u32::MAX, [0; 3]), None);
+    /// ```
+    pub fn into_parts(self) -> (u32, [usize; 3]) {
+        let message = WindleHandleMessage::from(self);
+        let fields = [message.a, message.b, message.c];
+        (message.kind, fields.map(|field| field as usize))
+    }
+
+    /// Reassembles a handle split by [`into_parts`](Self::into_parts).
+    ///
+    /// Returns `None` if `tag` is unknown or a slot doesn't fit its field.
+    pub fn from_parts(tag: u32, fields: [usize; 3]) -> Option<Self> {
+        let [a, b, c] = fields.map(|field| field as u64);
+        RawWindowHandle::try_from(WindleHandleMessage {
+            kind: tag,
+            reserved: 0,
+            a,
+            b,
+            c,
+        })
+        .ok()
+    }
+}
+
+fn address(ptr: *mut c_void) -> u64 {
+    ptr.expose_provenance() as u64
+}
+
+/// Rebuilds the pointer stored in the slot of `field`, failing if the address doesn't fit in a
+/// `usize` (on 32-bit targets).
+fn pointer(address: u64, field: &'static str) -> Result<*mut c_void, HandleError> {
+    let address = usize::try_from(address).map_err(|_| HandleError::InvalidField(field))?;
+    Ok(ptr::with_exposed_provenance_mut(address))
+}
@@ -0,0 +1,74 @@
+use alloc::boxed::Box;
+use core::fmt;
+
+use crate::{HasRawDisplayHandle, RawDisplayHandle};
+
+/// A display handle that owns the underlying connection and closes it when dropped.
+///
+/// Some backends (e.g. GBM or DRM) hand out a display handle for a device or file descriptor that
+/// must be closed when it's no longer used. `OwnedDisplayHandle` stores the raw handle together
+/// with a closure, provided at construction, that runs exactly once when it is dropped.
+///
+/// The raw handle returned by [`as_raw`](Self::as_raw) (or through [`HasRawDisplayHandle`]) is
+/// only valid while the `OwnedDisplayHandle` is alive; it must not be used after the drop closure
+/// ran.
+///
+/// Requires the `alloc` feature (enabled by `std`).
+///
+/// ```
+/// # use std::cell::Cell;
+/// # use std::rc::Rc;
+/// # use windle::{HasRawDisplayHandle, OwnedDisplayHandle, RawDisplayHandle};
+/// let closed = Rc::new(Cell::new(0));
+///
+/// let display = OwnedDisplayHandle::new(RawDisplayHandle::windows(), {
+///     let closed = Rc::clone(&closed);
+///     move |_| closed.set(closed.get() + 1)
+/// });
+/// assert_eq!(display.raw_display_handle(), RawDisplayHandle::windows());
+/// assert_eq!(closed.get(), 0);
+///
+/// drop(display);
+/// assert_eq!(closed.get(), 1);
+/// ```
+pub struct OwnedDisplayHandle {
+    raw: RawDisplayHandle,
+    close: Option<Box<dyn FnOnce(RawDisplayHandle)>>,
+}
+
+impl OwnedDisplayHandle {
+    /// Takes ownership of `raw`, calling `close` with it when dropped.
+    pub fn new(raw: RawDisplayHandle, close: impl FnOnce(RawDisplayHandle) + 'static) -> Self {
+        Self {
+            raw,
+            close: Some(Box::new(close)),
+        }
+    }
+
+    /// Returns the raw handle, which must not outlive `self`.
+    pub fn as_raw(&self) -> RawDisplayHandle {
+        self.raw
+    }
+}
+
+impl HasRawDisplayHandle for OwnedDisplayHandle {
+    fn raw_display_handle(&self) -> RawDisplayHandle {
+        self.raw
+    }
+}
+
+impl Drop for OwnedDisplayHandle {
+    fn drop(&mut self) {
+        if let Some(close) = self.close.take() {
+            close(self.raw);
+        }
+    }
+}
+
+impl fmt::Debug for OwnedDisplayHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OwnedDisplayHandle")
+            .field("raw", &self.raw)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,215 @@
+use core::ffi::c_void;
+use core::fmt;
+use core::ptr;
+use core::str::{FromStr, SplitWhitespace};
+
+use crate::{RawWindowHandle, RawWindowHandleKind, Win32WindowHandle, WinRTWindowHandle};
+
+/// An error returned when parsing a [`RawWindowHandle`] from a string fails.
+///
+/// The textual form of a handle is its [kind name](RawWindowHandleKind::name) followed by
+/// whitespace-separated `field=value` pairs, as produced by its `Display` implementation:
+///
+/// ```
+/// # use windle::{RawWindowHandle, Win32WindowHandle};
+/// let handle = RawWindowHandle::from(Win32WindowHandle::from_hwnd(0x1000).unwrap());
+/// assert_eq!(handle.to_string(), "Win32 hwnd=0x1000 hinstance=0x0 composition_visual=0x0");
+/// assert_eq!("Win32 hwnd=0x1000 hinstance=0x0 composition_visual=0x0".parse(), Ok(handle));
+/// ```
+///
+/// Values are hexadecimal with a `0x` prefix, or decimal otherwise.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HandleParseError {
+    /// The input is empty or doesn't start with a known kind name.
+    ///
+    /// ```
+    /// # use windle::{HandleParseError, RawWindowHandle};
+    /// assert_eq!("Xlib window=1".parse::<RawWindowHandle>(), Err(HandleParseError::UnknownPrefix));
+    /// assert_eq!("".parse::<RawWindowHandle>(), Err(HandleParseError::UnknownPrefix));
+    /// ```
+    UnknownPrefix,
+    /// A field of the handle's kind is not given.
+    ///
+    /// ```
+    /// # use windle::{HandleParseError, RawWindowHandle};
+    /// assert_eq!(
+    ///     "Win32 hwnd=0x1000".parse::<RawWindowHandle>(),
+    ///     Err(HandleParseError::MissingField("hinstance")),
+    /// );
+    /// ```
+    MissingField(&'static str),
+    /// A token is not a `field=value` pair naming a field of the handle's kind.
+    ///
+    /// ```
+    /// # use windle::{HandleParseError, RawWindowHandle};
+    /// assert_eq!(
+    ///     "WinRT hwnd=0x1000".parse::<RawWindowHandle>(),
+    ///     Err(HandleParseError::UnexpectedField),
+    /// );
+    /// ```
+    UnexpectedField,
+    /// A field of the handle's kind is given more than once.
+    ///
+    /// ```
+    /// # use windle::{HandleParseError, RawWindowHandle};
+    /// assert_eq!(
+    ///     "Win32 hwnd=1 hwnd=2 hinstance=0 composition_visual=0".parse::<RawWindowHandle>(),
+    ///     Err(HandleParseError::DuplicateField("hwnd")),
+    /// );
+    /// ```
+    DuplicateField(&'static str),
+    /// A field value is not a valid integer.
+    ///
+    /// ```
+    /// # use windle::{HandleParseError, RawWindowHandle};
+    /// assert_eq!(
+    ///     "Win32 hwnd=0xzz hinstance=0 composition_visual=0".parse::<RawWindowHandle>(),
+    ///     Err(HandleParseError::InvalidInteger),
+    /// );
+    /// ```
+    InvalidInteger,
+}
+
+impl fmt::Display for HandleParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownPrefix => f.write_str("unknown window handle kind"),
+            Self::MissingField(field) => write!(f, "missing field `{field}`"),
+            Self::UnexpectedField => f.write_str("unexpected field"),
+            Self::DuplicateField(field) => write!(f, "duplicate field `{field}`"),
+            Self::InvalidInteger => f.write_str("invalid integer value"),
+        }
+    }
+}
+
+impl core::error::Error for HandleParseError {}
+
+impl fmt::Display for RawWindowHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_to(f)
+    }
+}
+
+impl RawWindowHandle {
+    /// Writes the textual form of the handle to `w`, exactly as its `Display` implementation.
+    ///
+    /// Unlike `to_string()`, this never allocates, so it works in `no_std` code, e.g. to format into
+    /// a fixed-capacity buffer.
+    ///
+    /// ```
+    /// # use core::fmt::Write;
+    /// # use windle::{RawWindowHandle, Win32WindowHandle};
+    /// struct StackBuffer {
+    ///     bytes: [u8; 128],
+    ///     len: usize,
+    /// }
+    ///
+    /// impl Write for StackBuffer {
+    ///     fn write_str(&mut self, s: &str) -> core::fmt::Result {
+    ///         let end = self.len + s.len();
+    ///         self.bytes.get_mut(self.len..end).ok_or(core::fmt::Error)?.copy_from_slice(s.as_bytes());
+    ///         self.len = end;
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let handle = RawWindowHandle::from(Win32WindowHandle::from_hwnd(0x1000).unwrap());
+    /// let mut buffer = StackBuffer { bytes: [0; 128], len: 0 };
+    /// handle.write_to(&mut buffer).unwrap();
+    /// assert_eq!(core::str::from_utf8(&buffer.bytes[..buffer.len]), Ok(handle.to_string().as_str()));
+    /// ```
+    pub fn write_to<W: fmt::Write>(&self, w: &mut W) -> fmt::Result {
+        w.write_str(self.kind().name())?;
+        match self {
+            RawWindowHandle::Win32(handle) => write!(
+                w,
+                " hwnd={:p} hinstance={:p} composition_visual={:p}",
+                handle.hwnd, handle.hinstance, handle.composition_visual
+            ),
+            RawWindowHandle::WinRT(handle) => write!(
+                w,
+                " core_window={:p} swap_chain_panel={:p} panel_index={}",
+                handle.core_window, handle.swap_chain_panel, handle.panel_index
+            ),
+        }
+    }
+}
+
+impl FromStr for RawWindowHandle {
+    type Err = HandleParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut tokens = s.split_whitespace();
+        let kind = tokens
+            .next()
+            .and_then(|name| {
+                RawWindowHandleKind::ALL
+                    .iter()
+                    .copied()
+                    .find(|kind| kind.name() == name)
+            })
+            .ok_or(HandleParseError::UnknownPrefix)?;
+
+        match kind {
+            RawWindowHandleKind::Win32 => {
+                let [hwnd, hinstance, composition_visual] = parse_fields(tokens, kind)?;
+                let mut handle = Win32WindowHandle::from((pointer(hwnd), pointer(hinstance)));
+                handle.composition_visual = pointer(composition_visual);
+                Ok(RawWindowHandle::Win32(handle))
+            }
+            RawWindowHandleKind::WinRT => {
+                let [core_window, swap_chain_panel, panel_index] = parse_fields(tokens, kind)?;
+                let panel_index =
+                    u32::try_from(panel_index).map_err(|_| HandleParseError::InvalidInteger)?;
+                Ok(RawWindowHandle::WinRT(
+                    WinRTWindowHandle::from((pointer(core_window), pointer(swap_chain_panel)))
+                        .with_panel_index(panel_index),
+                ))
+            }
+        }
+    }
+}
+
+/// Parses the `field=value` pairs of a handle of `kind`, returning the values in the order of
+/// its [field names](RawWindowHandleKind::field_names).
+fn parse_fields<const N: usize>(
+    tokens: SplitWhitespace<'_>,
+    kind: RawWindowHandleKind,
+) -> Result<[usize; N], HandleParseError> {
+    let names = kind.field_names();
+    debug_assert_eq!(names.len(), N);
+
+    let mut values = [None; N];
+    for token in tokens {
+        let (name, value) = token
+            .split_once('=')
+            .ok_or(HandleParseError::UnexpectedField)?;
+        let index = names
+            .iter()
+            .position(|&field| field == name)
+            .ok_or(HandleParseError::UnexpectedField)?;
+        if values[index].is_some() {
+            return Err(HandleParseError::DuplicateField(names[index]));
+        }
+        values[index] = Some(parse_integer(value)?);
+    }
+
+    let mut fields = [0; N];
+    for (index, value) in values.into_iter().enumerate() {
+        fields[index] = value.ok_or(HandleParseError::MissingField(names[index]))?;
+    }
+    Ok(fields)
+}
+
+fn parse_integer(value: &str) -> Result<usize, HandleParseError> {
+    match value.strip_prefix("0x") {
+        Some(hex) => usize::from_str_radix(hex, 16),
+        None => value.parse(),
+    }
+    .map_err(|_| HandleParseError::InvalidInteger)
+}
+
+fn pointer(address: usize) -> *mut c_void {
+    ptr::with_exposed_provenance_mut(address)
+}
@@ -0,0 +1,36 @@
+use core::ptr;
+
+use crate::{RawWindowHandle, Win32WindowHandle};
+
+/// The address stored in the `hwnd` of a [poisoned](RawWindowHandle::poisoned) handle.
+const POISON: usize = 0xDEAD_BEEF;
+
+impl RawWindowHandle {
+    /// Returns a structurally valid handle flagged as poisoned, for negative tests.
+    ///
+    /// The handle is a [`RawWindowHandle::Win32`] whose `hwnd` is the sentinel address
+    /// `0xDEADBEEF` and whose other fields are null. It passes
+    /// [`check_consistency`](Self::check_consistency) but must never be handed to the OS.
+    ///
+    /// ```
+    /// # use windle::{RawWindowHandle, Win32WindowHandle};
+    /// let poisoned = RawWindowHandle::poisoned();
+    /// assert!(poisoned.is_poisoned());
+    /// assert!(!poisoned.is_null());
+    /// assert_eq!(poisoned.check_consistency(), Ok(()));
+    ///
+    /// let handle = RawWindowHandle::from(Win32WindowHandle::from_hwnd(0x1000).unwrap());
+    /// assert!(!handle.is_poisoned());
+    /// ```
+    pub fn poisoned() -> RawWindowHandle {
+        let mut handle = Win32WindowHandle::empty();
+        handle.hwnd = ptr::without_provenance_mut(POISON);
+        RawWindowHandle::Win32(handle)
+    }
+
+    /// Returns `true` if any native pointer of this handle is the [poisoned](Self::poisoned)
+    /// sentinel.
+    pub fn is_poisoned(&self) -> bool {
+        self.pointers().any(|pointer| pointer.addr() == POISON)
+    }
+}
@@ -0,0 +1,75 @@
+use core::fmt;
+
+use crate::{RawWindowHandle, Win32WindowHandle, WinRTWindowHandle};
+
+impl RawWindowHandle {
+    /// Returns a copy of this handle with every field reset to its empty value, keeping the
+    /// variant.
+    ///
+    /// This is meant for scrubbing handles before logging or serializing them in production. As
+    /// with [`display_redacted`](Self::display_redacted), non-pointer fields such as
+    /// [`panel_index`](crate::WinRTWindowHandle::panel_index) are redacted as well, since they
+    /// can still tell windows apart.
+    ///
+    /// ```
+    /// # use windle::{RawWindowHandle, Win32WindowHandle, WinRTWindowHandle};
+    /// # let ptr = |addr: usize| core::ptr::without_provenance_mut::<core::ffi::c_void>(addr);
+    /// let handle = RawWindowHandle::from(Win32WindowHandle::from_hwnd(0x1000).unwrap());
+    /// let redacted = handle.redacted();
+    /// assert_eq!(redacted.kind(), handle.kind());
+    /// assert_eq!(redacted, RawWindowHandle::Win32(Win32WindowHandle::empty()));
+    ///
+    /// let handle = RawWindowHandle::from(
+    ///     WinRTWindowHandle::empty().with_swap_chain_panel(ptr(0x1000)).with_panel_index(2),
+    /// );
+    /// assert_eq!(handle.redacted(), RawWindowHandle::WinRT(WinRTWindowHandle::empty()));
+    /// ```
+    pub fn redacted(&self) -> RawWindowHandle {
+        match self {
+            RawWindowHandle::Win32(_) => RawWindowHandle::Win32(Win32WindowHandle::empty()),
+            RawWindowHandle::WinRT(_) => RawWindowHandle::WinRT(WinRTWindowHandle::empty()),
+        }
+    }
+
+    /// Returns a value that formats like the textual form of this handle, with every field value
+    /// replaced by `<redacted>`.
+    ///
+    /// ```
+    /// # use windle::{RawWindowHandle, Win32WindowHandle};
+    /// let handle = RawWindowHandle::from(Win32WindowHandle::from_hwnd(0x1000).unwrap());
+    /// assert_eq!(
+    ///     handle.display_redacted().to_string(),
+    ///     "Win32 hwnd=<redacted> hinstance=<redacted> composition_visual=<redacted>",
+    /// );
+    /// ```
+    pub fn display_redacted(&self) -> RedactedWindowHandle {
+        RedactedWindowHandle { handle: *self }
+    }
+}
+
+/// A [`RawWindowHandle`] that formats without revealing its fields.
+///
+/// Returned by [`RawWindowHandle::display_redacted`]; both `Display` and `Debug` print the kind
+/// name followed by `field=<redacted>` for each field, pointer or not (see
+/// [`RawWindowHandle::redacted`]).
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RedactedWindowHandle {
+    handle: RawWindowHandle,
+}
+
+impl fmt::Display for RedactedWindowHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = self.handle.kind();
+        f.write_str(kind.name())?;
+        for field in kind.field_names() {
+            write!(f, " {field}=<redacted>")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for RedactedWindowHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,100 @@
+use std::collections::{hash_map, HashMap};
+use std::hash::Hash;
+
+use crate::{HandleError, RawHandlePair};
+
+/// A map from user tokens (e.g. entity ids) to the handles of their window.
+///
+/// Unlike a bare `HashMap<K, RawHandlePair>`, pairs with a null window handle (see
+/// [`RawWindowHandle::is_null`](crate::RawWindowHandle::is_null)) are rejected on registration.
+///
+/// ```
+/// # use windle::{HandleError, HandleRegistry, RawDisplayHandle, RawHandlePair, Win32WindowHandle};
+/// let window = Win32WindowHandle::from_hwnd(1).unwrap().into();
+/// let pair = RawHandlePair::new(window, RawDisplayHandle::windows());
+///
+/// let mut registry = HandleRegistry::new();
+/// assert_eq!(registry.register(7_u32, pair), Ok(None));
+/// assert_eq!(registry.get(&7), Some(&pair));
+/// assert_eq!(registry.pairs().count(), 1);
+///
+/// let null = RawHandlePair::new(
+///     windle::RawWindowHandle::Win32(Win32WindowHandle::empty()),
+///     RawDisplayHandle::windows(),
+/// );
+/// assert_eq!(registry.register(8, null), Err(HandleError::Unavailable));
+///
+/// assert_eq!(registry.remove(&7), Some(pair));
+/// assert!(registry.is_empty());
+/// ```
+#[derive(Debug, Clone)]
+pub struct HandleRegistry<K> {
+    handles: HashMap<K, RawHandlePair>,
+}
+
+impl<K> Default for HandleRegistry<K> {
+    fn default() -> Self {
+        Self {
+            handles: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Hash + Eq> HandleRegistry<K> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Associates `pair` with `key`, returning the pair previously registered for it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HandleError::Unavailable`] if the window handle of `pair` is null; the registry is
+    /// left unchanged.
+    pub fn register(
+        &mut self,
+        key: K,
+        pair: RawHandlePair,
+    ) -> Result<Option<RawHandlePair>, HandleError> {
+        if pair.window.is_null() {
+            return Err(HandleError::Unavailable);
+        }
+        Ok(self.handles.insert(key, pair))
+    }
+
+    pub fn get(&self, key: &K) -> Option<&RawHandlePair> {
+        self.handles.get(key)
+    }
+
+    /// Unregisters `key`, returning its pair.
+    pub fn remove(&mut self, key: &K) -> Option<RawHandlePair> {
+        self.handles.remove(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.handles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.handles.is_empty()
+    }
+
+    /// Iterates over the registered keys and pairs, in arbitrary order.
+    pub fn iter(&self) -> hash_map::Iter<'_, K, RawHandlePair> {
+        self.handles.iter()
+    }
+
+    /// Iterates over the registered pairs, e.g. to recreate every surface at once.
+    pub fn pairs(&self) -> hash_map::Values<'_, K, RawHandlePair> {
+        self.handles.values()
+    }
+}
+
+impl<'a, K> IntoIterator for &'a HandleRegistry<K> {
+    type Item = (&'a K, &'a RawHandlePair);
+    type IntoIter = hash_map::Iter<'a, K, RawHandlePair>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.handles.iter()
+    }
+}
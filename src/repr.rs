@@ -0,0 +1,62 @@
+use crate::{RawWindowHandle, Win32WindowHandle, WinRTWindowHandle};
+
+/// The payload of a [`RawWindowHandle`], as laid out in memory.
+///
+/// A `RawWindowHandle` is `#[repr(C, u32)]`, i.e. a `u32` tag followed by this union. Which field
+/// is valid is given by the [tag](RawWindowHandle::tag):
+///
+/// | Tag | Field                 |
+/// |-----|-----------------------|
+/// | `0` | [`win32`](Self::win32) |
+/// | `1` | [`winrt`](Self::winrt) |
+///
+/// ```
+/// # use windle::{RawWindowHandle, RawWindowHandleKind, Win32WindowHandle, WinRTWindowHandle};
+/// # let ptr = |addr: usize| core::ptr::without_provenance_mut::<core::ffi::c_void>(addr);
+/// let handles = [
+///     RawWindowHandle::from(Win32WindowHandle::from((ptr(0x1000), ptr(0x2000)))),
+///     RawWindowHandle::from(WinRTWindowHandle::empty().with_core_window(ptr(0x3000))),
+/// ];
+///
+/// for handle in handles {
+///     // SAFETY: only the field matching the tag is read.
+///     let (tag, payload) = unsafe { handle.as_union() };
+///     assert_eq!(tag, handle.tag());
+///     match tag {
+///         0 => assert_eq!(RawWindowHandle::Win32(unsafe { payload.win32 }), handle),
+///         1 => assert_eq!(RawWindowHandle::WinRT(unsafe { payload.winrt }), handle),
+///         _ => unreachable!(),
+///     }
+/// }
+/// ```
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union HandleUnion {
+    pub win32: Win32WindowHandle,
+    pub winrt: WinRTWindowHandle,
+}
+
+/// The layout of a `#[repr(C, u32)]` [`RawWindowHandle`].
+#[repr(C)]
+struct TaggedHandle {
+    tag: u32,
+    payload: HandleUnion,
+}
+
+impl RawWindowHandle {
+    /// Returns the tag of the handle and a view of its payload as a [`HandleUnion`], so it can be
+    /// dispatched on without a `match`.
+    ///
+    /// The view borrows the handle itself: no fields are copied.
+    ///
+    /// # Safety
+    ///
+    /// Only the field of the union matching the returned tag may be read; reading another one
+    /// reinterprets the bytes of a different handle struct and may read uninitialized padding.
+    pub unsafe fn as_union(&self) -> (u32, &HandleUnion) {
+        // SAFETY: `RawWindowHandle` is `#[repr(C, u32)]`, which is defined to have the layout of
+        // `TaggedHandle`.
+        let tagged = unsafe { &*(self as *const RawWindowHandle).cast::<TaggedHandle>() };
+        (tagged.tag, &tagged.payload)
+    }
+}
@@ -0,0 +1,46 @@
+use crate::{HasRawWindowHandle, RawWindowHandle};
+
+/// A window handle shipped together with the window's scale factor.
+///
+/// This lets a toolkit hand the scale factor to a renderer along with the handle, saving it a
+/// round-trip query to the OS. A scale factor of `0.0` or `NaN` means the scale factor is unknown.
+///
+/// ```
+/// # use windle::{HasRawWindowHandle, RawWindowHandle, ScaledWindowHandle, Win32WindowHandle};
+/// let handle = RawWindowHandle::from(Win32WindowHandle::from_hwnd(0x1000).unwrap());
+/// let scaled = ScaledWindowHandle::new(handle, 1.5);
+/// assert_eq!(scaled.raw_window_handle(), handle);
+/// assert_eq!(scaled.scale_factor(), Some(1.5));
+///
+/// assert_eq!(ScaledWindowHandle::new(handle, 0.0).scale_factor(), None);
+/// assert_eq!(ScaledWindowHandle::new(handle, f64::NAN).scale_factor(), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScaledWindowHandle {
+    handle: RawWindowHandle,
+    scale_factor: f64,
+}
+
+impl ScaledWindowHandle {
+    pub fn new(handle: RawWindowHandle, scale_factor: f64) -> Self {
+        Self {
+            handle,
+            scale_factor,
+        }
+    }
+
+    /// Returns the scale factor, or `None` if it is unknown (`0.0` or `NaN`).
+    pub fn scale_factor(&self) -> Option<f64> {
+        if self.scale_factor == 0.0 || self.scale_factor.is_nan() {
+            None
+        } else {
+            Some(self.scale_factor)
+        }
+    }
+}
+
+impl HasRawWindowHandle for ScaledWindowHandle {
+    fn raw_window_handle(&self) -> RawWindowHandle {
+        self.handle
+    }
+}
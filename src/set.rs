@@ -0,0 +1,88 @@
+use std::collections::{hash_set, HashSet};
+
+use crate::{HandleError, RawHandlePair, RawWindowHandle};
+
+/// A set of distinct, non-null window handles.
+///
+/// Unlike a bare `HashSet<RawWindowHandle>`, null handles (see [`RawWindowHandle::is_null`]) are
+/// rejected on insertion, so every handle yielded by [`iter`](Self::iter) refers to a window.
+///
+/// ```
+/// # use windle::{HandleError, RawWindowHandle, Win32WindowHandle, WindowHandleSet};
+/// let handle = RawWindowHandle::from(Win32WindowHandle::from_hwnd(1).unwrap());
+///
+/// let mut set = WindowHandleSet::new();
+/// assert_eq!(set.insert(handle), Ok(true));
+/// assert_eq!(set.insert(handle), Ok(false));
+/// assert!(set.contains(&handle));
+/// assert_eq!(set.len(), 1);
+///
+/// let null = RawWindowHandle::Win32(Win32WindowHandle::empty());
+/// assert_eq!(set.insert(null), Err(HandleError::Unavailable));
+/// assert_eq!(set.len(), 1);
+///
+/// assert!(set.remove(&handle));
+/// assert!(set.is_empty());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WindowHandleSet {
+    handles: HashSet<RawWindowHandle>,
+}
+
+impl WindowHandleSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a handle to the set, returning whether it wasn't already present.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HandleError::Unavailable`] if the handle is null.
+    pub fn insert(&mut self, handle: RawWindowHandle) -> Result<bool, HandleError> {
+        if handle.is_null() {
+            return Err(HandleError::Unavailable);
+        }
+        Ok(self.handles.insert(handle))
+    }
+
+    /// Adds the window handle of a pair to the set; the display handle is not stored.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HandleError::Unavailable`] if the window handle is null.
+    pub fn insert_pair(&mut self, pair: RawHandlePair) -> Result<bool, HandleError> {
+        self.insert(pair.window)
+    }
+
+    pub fn contains(&self, handle: &RawWindowHandle) -> bool {
+        self.handles.contains(handle)
+    }
+
+    /// Removes a handle from the set, returning whether it was present.
+    pub fn remove(&mut self, handle: &RawWindowHandle) -> bool {
+        self.handles.remove(handle)
+    }
+
+    pub fn len(&self) -> usize {
+        self.handles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.handles.is_empty()
+    }
+
+    /// Iterates over the handles in the set, in arbitrary order.
+    pub fn iter(&self) -> hash_set::Iter<'_, RawWindowHandle> {
+        self.handles.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a WindowHandleSet {
+    type Item = &'a RawWindowHandle;
+    type IntoIter = hash_set::Iter<'a, RawWindowHandle>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,29 @@
+use core::ffi::CStr;
+
+use crate::RawDisplayHandle;
+
+const VK_KHR_SURFACE: &CStr = c"VK_KHR_surface";
+const VK_KHR_WIN32_SURFACE: &CStr = c"VK_KHR_win32_surface";
+
+/// Returns the Vulkan instance extensions needed to create a surface on `display`.
+///
+/// Enable these when creating the `VkInstance`, before calling the platform's
+/// `vkCreate*SurfaceKHR` function:
+///
+/// | Variant                       | Extensions                                     |
+/// |-------------------------------|------------------------------------------------|
+/// | [`RawDisplayHandle::Windows`] | `VK_KHR_surface`, `VK_KHR_win32_surface`       |
+///
+/// The names are plain C strings, so they can be passed to any Vulkan binding (e.g. through
+/// `CStr::as_ptr`).
+///
+/// ```
+/// # use windle::{required_instance_extensions, RawDisplayHandle};
+/// let extensions = required_instance_extensions(&RawDisplayHandle::windows());
+/// assert_eq!(extensions, [c"VK_KHR_surface", c"VK_KHR_win32_surface"]);
+/// ```
+pub fn required_instance_extensions(display: &RawDisplayHandle) -> &'static [&'static CStr] {
+    match display {
+        RawDisplayHandle::Windows(_) => &[VK_KHR_SURFACE, VK_KHR_WIN32_SURFACE],
+    }
+}
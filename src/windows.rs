@@ -1,5 +1,10 @@
 use core::ffi::c_void;
-use core::ptr;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::Range;
+use core::ptr::{self, NonNull};
+
+use crate::HandleError;
 
 /// Raw display handle for Windows.
 ///
@@ -7,18 +12,35 @@ use core::ptr;
 ///
 /// ## Construction
 /// ```
-/// # use raw_window_handle::WindowsDisplayHandle;
+/// # use windle::WindowsDisplayHandle;
 /// let mut display_handle = WindowsDisplayHandle::empty();
 /// /* set fields */
 /// ```
-
+///
+/// [`Default`] is equivalent to [`empty`](Self::empty), i.e. an unspecified monitor:
+///
+/// ```
+/// # use windle::WindowsDisplayHandle;
+/// assert_eq!(WindowsDisplayHandle::default(), WindowsDisplayHandle::empty());
+/// ```
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct WindowsDisplayHandle;
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WindowsDisplayHandle {
+    /// An optional `HMONITOR` handle of the monitor the display refers to; null if unspecified.
+    pub hmonitor: *mut c_void,
+}
 
 impl WindowsDisplayHandle {
     pub fn empty() -> Self {
-        Self
+        Self {
+            hmonitor: ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for WindowsDisplayHandle {
+    fn default() -> Self {
+        Self::empty()
     }
 }
 
@@ -26,18 +48,76 @@ impl WindowsDisplayHandle {
 ///
 /// ## Construction
 /// ```
-/// # use raw_window_handle::Win32WindowHandle;
+/// # use windle::Win32WindowHandle;
 /// let mut window_handle = Win32WindowHandle::empty();
 /// /* set fields */
 /// ```
-
+///
+/// ## Pointer provenance
+///
+/// The fields are stored as pointers, so a handle that is built from a pointer and read back as a
+/// pointer keeps the provenance of the original. This is the path to use when possible: it runs
+/// clean under Miri, including with `-Zmiri-strict-provenance`.
+///
+/// ```
+/// # use windle::Win32WindowHandle;
+/// let mut window = 0u8;
+/// let hwnd: *mut core::ffi::c_void = (&mut window as *mut u8).cast();
+///
+/// let mut window_handle = Win32WindowHandle::empty();
+/// window_handle.hwnd = hwnd;
+/// assert_eq!(window_handle.hwnd, hwnd);
+/// assert_eq!(unsafe { *window_handle.hwnd.cast::<u8>() }, 0);
+/// ```
+///
+/// Casting a field to an integer (`hwnd as isize`) and back discards its provenance. Callers that
+/// really need the integer form of an `HWND` (e.g. to hand it to a scripting layer) should use
+/// [`expose_hwnd`](Self::expose_hwnd) and [`from_exposed_hwnd`](Self::from_exposed_hwnd), which go
+/// through `<*const T>::expose_provenance` and
+/// [`with_exposed_provenance_mut`](ptr::with_exposed_provenance_mut). That round trip relies on
+/// permissive provenance only: Miri warns about it by default and rejects it under
+/// `-Zmiri-strict-provenance`. The same goes for [`from_hwnd`](Self::from_hwnd).
+///
+/// ```
+/// # use windle::Win32WindowHandle;
+/// let mut window = 0u8;
+/// let mut window_handle = Win32WindowHandle::empty();
+/// window_handle.hwnd = (&mut window as *mut u8).cast();
+///
+/// let exposed = window_handle.expose_hwnd();
+/// let window_handle = Win32WindowHandle::from_exposed_hwnd(exposed);
+/// assert_eq!(unsafe { *window_handle.hwnd.cast::<u8>() }, 0);
+/// ```
+///
+/// ## Composition
+///
+/// DirectComposition apps don't present to the `HWND` itself but to a swap chain set as the
+/// content of a visual, whose tree is bound to the window with `CreateTargetForHwnd`. Such a
+/// handle carries that visual in [`composition_visual`](Self::composition_visual): when it is
+/// non-null, a renderer should create its swap chain with `CreateSwapChainForComposition` and set
+/// it as the visual's content, instead of calling `CreateSwapChainForHwnd` on `hwnd`.
+///
+/// ```
+/// # use windle::{RawWindowHandle, Win32WindowHandleBuilder};
+/// # let ptr = |addr: usize| core::ptr::without_provenance_mut::<core::ffi::c_void>(addr);
+/// let window_handle = Win32WindowHandleBuilder::new()
+///     .hwnd(ptr(0x1000))
+///     .composition_visual(ptr(0x2000))
+///     .build()
+///     .unwrap();
+/// let handle = RawWindowHandle::from(window_handle);
+/// assert!(matches!(handle, RawWindowHandle::Win32(h) if h.composition_visual == ptr(0x2000)));
+/// ```
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Win32WindowHandle {
     /// A Win32 `HWND` handle
     pub hwnd: *mut c_void,
     /// The `HINSTANCE` associated with this type's `HWND`
     pub hinstance: *mut c_void,
+    /// An optional `IDCompositionVisual` hosted in the `HWND`, to render into instead of the
+    /// window itself.
+    pub composition_visual: *mut c_void,
 }
 
 impl Win32WindowHandle {
@@ -45,30 +125,860 @@ impl Win32WindowHandle {
         Self {
             hwnd: ptr::null_mut(),
             hinstance: ptr::null_mut(),
+            composition_visual: ptr::null_mut(),
+        }
+    }
+
+    /// Returns `true` if the handle doesn't refer to a window, i.e. its `HWND` is null.
+    pub fn is_null(&self) -> bool {
+        self.hwnd.is_null()
+    }
+
+    /// Checks that the fields of the handle make sense together.
+    ///
+    /// The rules are:
+    ///
+    /// - `hinstance` must not be `INVALID_HANDLE_VALUE` (`-1`), otherwise
+    ///   [`HandleError::InvalidField`] is returned.
+    /// - `hinstance` and `composition_visual` must not be set without an `hwnd`, otherwise
+    ///   [`HandleError::Inconsistent`] is returned.
+    ///
+    /// An empty handle is consistent.
+    ///
+    /// ```
+    /// # use windle::{HandleError, Win32WindowHandle};
+    /// # let ptr = |addr: isize| core::ptr::without_provenance_mut::<core::ffi::c_void>(addr as usize);
+    /// assert_eq!(Win32WindowHandle::empty().check_consistency(), Ok(()));
+    /// assert_eq!(Win32WindowHandle::from((ptr(0x1000), ptr(0x2000))).check_consistency(), Ok(()));
+    ///
+    /// assert_eq!(
+    ///     Win32WindowHandle::from((ptr(0x1000), ptr(-1))).check_consistency(),
+    ///     Err(HandleError::InvalidField("hinstance")),
+    /// );
+    /// assert!(matches!(
+    ///     Win32WindowHandle::from((ptr(0), ptr(0x2000))).check_consistency(),
+    ///     Err(HandleError::Inconsistent(_)),
+    /// ));
+    /// ```
+    pub fn check_consistency(&self) -> Result<(), HandleError> {
+        if self.hinstance.addr() == INVALID_HANDLE_VALUE {
+            return Err(HandleError::InvalidField("hinstance"));
+        }
+        if self.hwnd.is_null() && !self.hinstance.is_null() {
+            return Err(HandleError::Inconsistent(
+                "`hinstance` is set without an `hwnd`",
+            ));
+        }
+        if self.hwnd.is_null() && !self.composition_visual.is_null() {
+            return Err(HandleError::Inconsistent(
+                "`composition_visual` is set without an `hwnd`",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Creates a handle from an `HWND` received as an integer, e.g. from a scripting layer.
+    ///
+    /// Returns `None` if `hwnd` is `0`. The pointer is rebuilt with
+    /// [`with_exposed_provenance_mut`](ptr::with_exposed_provenance_mut).
+    ///
+    /// Note that this only checks for `0`: the caller remains responsible for `hwnd` naming a live
+    /// window, as stated in the docs of [`HasRawWindowHandle`](crate::HasRawWindowHandle).
+    ///
+    /// ```
+    /// # use windle::Win32WindowHandle;
+    /// assert_eq!(Win32WindowHandle::from_hwnd(0), None);
+    ///
+    /// let window_handle = Win32WindowHandle::from_hwnd(0x1234).unwrap();
+    /// assert_eq!(window_handle.hwnd as isize, 0x1234);
+    /// assert!(window_handle.hinstance.is_null());
+    /// ```
+    pub fn from_hwnd(hwnd: isize) -> Option<Self> {
+        if hwnd == 0 {
+            return None;
+        }
+
+        // SAFETY: `hwnd` was checked to be non-zero above.
+        Some(unsafe { Self::from_hwnd_unchecked(hwnd) })
+    }
+
+    /// Creates a handle from an `HWND` received as an integer, without checking it.
+    ///
+    /// # Safety
+    ///
+    /// `hwnd` must be the non-zero value of a live `HWND`. Users of the returned handle rely on a
+    /// non-null `hwnd` being valid.
+    ///
+    /// ```
+    /// # use windle::Win32WindowHandle;
+    /// let window_handle = unsafe { Win32WindowHandle::from_hwnd_unchecked(0x1234) };
+    /// assert_eq!(window_handle.hwnd as isize, 0x1234);
+    /// ```
+    pub unsafe fn from_hwnd_unchecked(hwnd: isize) -> Self {
+        Self::from_exposed_hwnd(hwnd as usize)
+    }
+
+    /// Creates a handle from an `HWND` stored as an untyped pointer, e.g. by an abstraction layer
+    /// that doesn't know about this struct's layout.
+    ///
+    /// Returns `None` if `hwnd` is null. The pointer is stored as-is, without going through an
+    /// integer, so unlike [`from_hwnd`](Self::from_hwnd) its provenance is kept.
+    ///
+    /// ```
+    /// # use windle::Win32WindowHandle;
+    /// # use core::ffi::c_void;
+    /// assert_eq!(Win32WindowHandle::from_generic(core::ptr::null_mut()), None);
+    ///
+    /// for addr in [0x1000, 0x2000, usize::MAX] {
+    ///     let hwnd = core::ptr::without_provenance_mut::<c_void>(addr);
+    ///     let window_handle = Win32WindowHandle::from_generic(hwnd).unwrap();
+    ///     assert_eq!(window_handle.to_generic(), hwnd);
+    /// }
+    /// ```
+    pub fn from_generic(hwnd: *mut c_void) -> Option<Self> {
+        if hwnd.is_null() {
+            return None;
+        }
+
+        let mut window_handle = Self::empty();
+        window_handle.hwnd = hwnd;
+        Some(window_handle)
+    }
+
+    /// Returns the `HWND` as an untyped pointer, the inverse of [`from_generic`](Self::from_generic).
+    pub fn to_generic(&self) -> *mut c_void {
+        self.hwnd
+    }
+
+    /// Replaces the [`hwnd`](Self::hwnd) in place, e.g. after the window was recreated.
+    ///
+    /// ```
+    /// # use windle::Win32WindowHandle;
+    /// # let hwnd = core::ptr::without_provenance_mut::<core::ffi::c_void>(0x1000);
+    /// let mut window_handle = Win32WindowHandle::empty();
+    /// window_handle.set_hwnd(hwnd);
+    /// assert_eq!(window_handle.hwnd, hwnd);
+    /// ```
+    pub fn set_hwnd(&mut self, hwnd: *mut c_void) {
+        self.hwnd = hwnd;
+    }
+
+    /// Exposes the provenance of [`hwnd`](Self::hwnd) and returns its address.
+    ///
+    /// This is only meant for a round trip through [`from_exposed_hwnd`](Self::from_exposed_hwnd),
+    /// which needs permissive provenance (see [Pointer provenance](Self#pointer-provenance)).
+    pub fn expose_hwnd(&self) -> usize {
+        self.hwnd.expose_provenance()
+    }
+
+    /// Creates a handle whose [`hwnd`](Self::hwnd) is rebuilt from an address returned by
+    /// [`expose_hwnd`](Self::expose_hwnd), picking up the provenance exposed there.
+    ///
+    /// This uses [`with_exposed_provenance_mut`](ptr::with_exposed_provenance_mut), so it is not
+    /// supported by Miri's `-Zmiri-strict-provenance`; keep the pointer itself (as in
+    /// [`to_generic`](Self::to_generic)) when possible.
+    pub fn from_exposed_hwnd(hwnd: usize) -> Self {
+        Self {
+            hwnd: ptr::with_exposed_provenance_mut(hwnd),
+            ..Self::empty()
+        }
+    }
+}
+
+#[cfg(all(windows, feature = "win32-api"))]
+impl Win32WindowHandle {
+    /// Returns a display handle for the monitor nearest to [`hwnd`](Self::hwnd).
+    ///
+    /// This calls [`MonitorFromWindow`] with `MONITOR_DEFAULTTONEAREST`, so no monitor
+    /// enumeration is needed. It performs a syscall and is only available with the `win32-api`
+    /// feature. Returns `None` if `hwnd` is null or no monitor was found.
+    ///
+    /// [`MonitorFromWindow`]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-monitorfromwindow
+    ///
+    /// ```no_run
+    /// # #[cfg(all(windows, feature = "win32-api"))] {
+    /// # use windle::Win32WindowHandle;
+    /// # let window_handle = Win32WindowHandle::empty();
+    /// let display_handle = window_handle.monitor().expect("no monitor");
+    /// println!("{:?}", display_handle.hmonitor);
+    /// # }
+    /// ```
+    pub fn monitor(&self) -> Option<WindowsDisplayHandle> {
+        if self.hwnd.is_null() {
+            return None;
+        }
+
+        // SAFETY: `MonitorFromWindow` accepts any window handle.
+        let hmonitor = unsafe { ffi::MonitorFromWindow(self.hwnd, ffi::MONITOR_DEFAULTTONEAREST) };
+        if hmonitor.is_null() {
+            return None;
+        }
+
+        let mut display_handle = WindowsDisplayHandle::empty();
+        display_handle.hmonitor = hmonitor;
+        Some(display_handle)
+    }
+
+    /// Returns the DPI awareness context of [`hwnd`](Self::hwnd), as a `DPI_AWARENESS_CONTEXT`
+    /// handle value.
+    ///
+    /// This calls [`GetWindowDpiAwarenessContext`], which requires Windows 10 (version 1607) or
+    /// later. It performs a syscall and is only available with the `win32-api` feature. Returns
+    /// `None` if `hwnd` is null or not a valid window.
+    ///
+    /// [`GetWindowDpiAwarenessContext`]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getwindowdpiawarenesscontext
+    ///
+    /// ```no_run
+    /// # #[cfg(all(windows, feature = "win32-api"))] {
+    /// # use windle::Win32WindowHandle;
+    /// # let window_handle = Win32WindowHandle::empty();
+    /// if let Some(context) = window_handle.dpi_awareness_context() {
+    ///     println!("DPI awareness context: {context:#x}");
+    /// }
+    /// # }
+    /// ```
+    pub fn dpi_awareness_context(&self) -> Option<isize> {
+        if self.hwnd.is_null() {
+            return None;
+        }
+
+        // SAFETY: `GetWindowDpiAwarenessContext` accepts any window handle.
+        let context = unsafe { ffi::GetWindowDpiAwarenessContext(self.hwnd) };
+        if context.is_null() {
+            return None;
+        }
+
+        Some(context.expose_provenance() as isize)
+    }
+
+    /// Returns a handle to the active window of the calling thread, if any.
+    ///
+    /// This calls [`GetActiveWindow`], which only considers windows attached to the calling
+    /// thread's message queue: a window created by another thread is never returned, even if it
+    /// has focus. Use [`foreground`](Self::foreground) for the window the user is working with.
+    /// It performs a syscall and is only available with the `win32-api` feature.
+    ///
+    /// [`GetActiveWindow`]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getactivewindow
+    ///
+    /// ```no_run
+    /// # #[cfg(all(windows, feature = "win32-api"))] {
+    /// # use windle::Win32WindowHandle;
+    /// match Win32WindowHandle::active() {
+    ///     Some(window_handle) => println!("active window: {window_handle:?}"),
+    ///     None => println!("no active window on this thread"),
+    /// }
+    /// # }
+    /// ```
+    pub fn active() -> Option<Self> {
+        // SAFETY: `GetActiveWindow` has no preconditions.
+        let hwnd = unsafe { ffi::GetActiveWindow() };
+        Self::from_generic(hwnd)
+    }
+
+    /// Returns a handle to the foreground window, i.e. the window the user is currently working
+    /// with, if any.
+    ///
+    /// This calls [`GetForegroundWindow`]. Unlike [`active`](Self::active), the window may belong
+    /// to any thread or process. Returns `None` when no window is in the foreground, e.g. while
+    /// a window is losing activation. It performs a syscall and is only available with the
+    /// `win32-api` feature.
+    ///
+    /// [`GetForegroundWindow`]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getforegroundwindow
+    pub fn foreground() -> Option<Self> {
+        // SAFETY: `GetForegroundWindow` has no preconditions.
+        let hwnd = unsafe { ffi::GetForegroundWindow() };
+        Self::from_generic(hwnd)
+    }
+}
+
+#[cfg(all(windows, feature = "validate-os"))]
+impl Win32WindowHandle {
+    /// Returns `true` if [`hwnd`](Self::hwnd) identifies an existing window.
+    ///
+    /// This calls [`IsWindow`] and is only available with the `validate-os` feature. Note that an
+    /// `HWND` can be destroyed (and even reused) right after this returns.
+    ///
+    /// [`IsWindow`]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-iswindow
+    ///
+    /// ```no_run
+    /// # #[cfg(all(windows, feature = "validate-os"))] {
+    /// # use windle::Win32WindowHandle;
+    /// # let window_handle = Win32WindowHandle::empty();
+    /// if !window_handle.is_window_alive() {
+    ///     println!("the window was destroyed");
+    /// }
+    /// # }
+    /// ```
+    pub fn is_window_alive(&self) -> bool {
+        // SAFETY: `IsWindow` accepts any value, including null and stale handles.
+        unsafe { ffi::IsWindow(self.hwnd) != 0 }
+    }
+}
+
+/// Creates a handle from its `(hwnd, hinstance)` fields, in that order, without a
+/// [`composition_visual`](Win32WindowHandle::composition_visual).
+///
+/// ```
+/// # use windle::Win32WindowHandle;
+/// # let ptr = |addr: usize| core::ptr::without_provenance_mut::<core::ffi::c_void>(addr);
+/// let window_handle = Win32WindowHandle::from((ptr(0x1000), ptr(0x2000)));
+/// assert_eq!(window_handle.hwnd, ptr(0x1000));
+/// assert_eq!(window_handle.hinstance, ptr(0x2000));
+/// ```
+impl From<(*mut c_void, *mut c_void)> for Win32WindowHandle {
+    fn from((hwnd, hinstance): (*mut c_void, *mut c_void)) -> Self {
+        Self {
+            hwnd,
+            hinstance,
+            ..Self::empty()
         }
     }
 }
 
+/// Creates a handle from a non-null `HWND`, so [`is_null`](Win32WindowHandle::is_null) is
+/// guaranteed to be `false`.
+///
+/// ```
+/// # use core::ptr::NonNull;
+/// # use windle::Win32WindowHandle;
+/// let window_handle = Win32WindowHandle::from(NonNull::<u8>::dangling().cast());
+/// assert!(!window_handle.is_null());
+/// ```
+impl From<NonNull<c_void>> for Win32WindowHandle {
+    fn from(hwnd: NonNull<c_void>) -> Self {
+        let mut window_handle = Self::empty();
+        window_handle.hwnd = hwnd.as_ptr();
+        window_handle
+    }
+}
+
+/// A builder for a validated [`Win32WindowHandle`].
+///
+/// [`build`](Self::build) enforces the following rules:
+///
+/// - `hwnd` must be set to a non-null value, otherwise [`HandleError::Unavailable`] is returned.
+/// - `hinstance` may be left null, but must not be `INVALID_HANDLE_VALUE` (`-1`), otherwise
+///   [`HandleError::InvalidField`] is returned.
+/// - If a range was set with [`with_pointer_range`](Self::with_pointer_range), the address of
+///   `hwnd` must fall inside it, otherwise [`HandleError::InvalidField`] is returned.
+///
+/// ```
+/// # use windle::{HandleError, Win32WindowHandleBuilder};
+/// # let ptr = |addr: isize| core::ptr::without_provenance_mut::<core::ffi::c_void>(addr as usize);
+/// let window_handle = Win32WindowHandleBuilder::new()
+///     .hwnd(ptr(0x1000))
+///     .hinstance(ptr(0x2000))
+///     .build()
+///     .unwrap();
+/// assert_eq!(window_handle.hwnd, ptr(0x1000));
+/// assert_eq!(window_handle.hinstance, ptr(0x2000));
+///
+/// assert_eq!(
+///     Win32WindowHandleBuilder::new().hinstance(ptr(0x2000)).build(),
+///     Err(HandleError::Unavailable),
+/// );
+/// assert_eq!(
+///     Win32WindowHandleBuilder::new().hwnd(ptr(0x1000)).hinstance(ptr(-1)).build(),
+///     Err(HandleError::InvalidField("hinstance")),
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Win32WindowHandleBuilder {
+    handle: Win32WindowHandle,
+    pointer_range: Option<(usize, usize)>,
+}
+
+impl Default for Win32WindowHandleBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Win32WindowHandleBuilder {
+    pub fn new() -> Self {
+        Self {
+            handle: Win32WindowHandle::empty(),
+            pointer_range: None,
+        }
+    }
+
+    /// Sets the `HWND` of the handle.
+    pub fn hwnd(mut self, hwnd: *mut c_void) -> Self {
+        self.handle.hwnd = hwnd;
+        self
+    }
+
+    /// Sets the `HINSTANCE` of the handle.
+    pub fn hinstance(mut self, hinstance: *mut c_void) -> Self {
+        self.handle.hinstance = hinstance;
+        self
+    }
+
+    /// Sets the composition visual of the handle.
+    pub fn composition_visual(mut self, composition_visual: *mut c_void) -> Self {
+        self.handle.composition_visual = composition_visual;
+        self
+    }
+
+    /// Restricts the address of `hwnd` to `range`, e.g. to reject kernel-space or obviously bogus
+    /// values coming from an untrusted process.
+    ///
+    /// This is an opt-in defensive check: an address inside the range is not guaranteed to be a
+    /// valid window.
+    ///
+    /// ```
+    /// # use windle::{HandleError, Win32WindowHandleBuilder};
+    /// # let ptr = |addr: usize| core::ptr::without_provenance_mut::<core::ffi::c_void>(addr);
+    /// let user_space = 0x1_0000..0x7fff_ffff_0000;
+    /// let builder = Win32WindowHandleBuilder::new().with_pointer_range(user_space);
+    ///
+    /// assert!(builder.hwnd(ptr(0x2_0000)).build().is_ok());
+    /// assert_eq!(
+    ///     builder.hwnd(ptr(0xffff_8000_0000_0000)).build(),
+    ///     Err(HandleError::InvalidField("hwnd")),
+    /// );
+    /// ```
+    pub fn with_pointer_range(mut self, range: Range<usize>) -> Self {
+        self.pointer_range = Some((range.start, range.end));
+        self
+    }
+
+    /// Validates the fields and builds the handle.
+    ///
+    /// # Errors
+    ///
+    /// See the [type-level documentation](Self) for the validation rules.
+    pub fn build(self) -> Result<Win32WindowHandle, HandleError> {
+        if self.handle.hwnd.is_null() {
+            return Err(HandleError::Unavailable);
+        }
+        if let Some((start, end)) = self.pointer_range {
+            if !(start..end).contains(&self.handle.hwnd.addr()) {
+                return Err(HandleError::InvalidField("hwnd"));
+            }
+        }
+        self.handle.check_consistency()?;
+        Ok(self.handle)
+    }
+}
+
+/// `INVALID_HANDLE_VALUE`, i.e. `-1` as a pointer-sized integer.
+const INVALID_HANDLE_VALUE: usize = usize::MAX;
+
 /// Raw window handle for WinRT
 ///
+/// A WinRT application renders either into its `CoreWindow` or into a XAML `SwapChainPanel`:
+///
+/// - Use [`core_window`](Self::core_window) for apps that own a `CoreWindow` and present to it
+///   directly (e.g. `CreateSwapChainForCoreWindow`).
+/// - Use [`swap_chain_panel`](Self::swap_chain_panel) for XAML apps that host the swap chain in a
+///   `SwapChainPanel` (e.g. `CreateSwapChainForComposition` + `ISwapChainPanelNative::SetSwapChain`).
+///
+/// Exactly one of the two pointers should be non-null.
+///
+/// An app compositing several sibling `SwapChainPanel`s can tell them apart with
+/// [`panel_index`](Self::panel_index), which defaults to `0`.
+///
+/// ## COM reference counting
+///
+/// Both pointers are COM interface pointers (`IUnknown`-derived). The handle does not own a
+/// reference: it neither calls `AddRef` when created nor `Release` when dropped, and copying it
+/// doesn't change the reference count. The object providing the handle must keep a reference for
+/// as long as the handle is used (see [`WinRTWindowHandleRef`] to have the compiler check this),
+/// and a consumer that stores the pointer beyond that must `AddRef` it itself and `Release` it
+/// when done.
+///
 /// ## Construction
 /// ```
-/// # use_raw_window_handle::WinRTWindowHandle;
+/// # use windle::WinRTWindowHandle;
 /// let mut window_handle = WinRTWindowHandle::empty();
 /// /* set fields */
 /// ```
-
+///
+/// ```
+/// # use windle::WinRTWindowHandle;
+/// # let core_window = core::ptr::without_provenance_mut::<core::ffi::c_void>(0x1000);
+/// # let panel = core::ptr::without_provenance_mut::<core::ffi::c_void>(0x2000);
+/// let window_handle = WinRTWindowHandle::empty().with_core_window(core_window);
+/// assert_eq!(window_handle.core_window, core_window);
+/// assert!(window_handle.swap_chain_panel.is_null());
+///
+/// let panel_handle = WinRTWindowHandle::empty().with_swap_chain_panel(panel);
+/// assert!(panel_handle.core_window.is_null());
+/// assert_eq!(panel_handle.swap_chain_panel, panel);
+/// assert_eq!(panel_handle.panel_index, 0);
+///
+/// let second_panel = WinRTWindowHandle::empty()
+///     .with_swap_chain_panel(panel)
+///     .with_panel_index(1);
+/// let handle = windle::RawWindowHandle::from(second_panel);
+/// assert!(matches!(handle, windle::RawWindowHandle::WinRT(h) if h.panel_index == 1));
+/// ```
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub struct WinRTWindowHandle {
     /// A WinRT `CoreWindow` handle.
     pub core_window: *mut c_void,
+    /// A XAML `SwapChainPanel` handle, queryable for `ISwapChainPanelNative`.
+    pub swap_chain_panel: *mut c_void,
+    /// The index disambiguating [`swap_chain_panel`](Self::swap_chain_panel) among sibling panels.
+    pub panel_index: u32,
 }
 
-impl WinRTWindowHandle  {
+impl WinRTWindowHandle {
     pub fn empty() -> Self {
         Self {
             core_window: ptr::null_mut(),
+            swap_chain_panel: ptr::null_mut(),
+            panel_index: 0,
+        }
+    }
+
+    /// Returns `true` if the handle refers to neither a `CoreWindow` nor a `SwapChainPanel`.
+    pub fn is_null(&self) -> bool {
+        self.core_window.is_null() && self.swap_chain_panel.is_null()
+    }
+
+    /// Checks that the fields of the handle make sense together.
+    ///
+    /// The rules, violations of which return [`HandleError::Inconsistent`], are:
+    ///
+    /// - `core_window` and `swap_chain_panel` must not both be set.
+    /// - `panel_index` must be `0` unless `swap_chain_panel` is set.
+    ///
+    /// An empty handle is consistent.
+    ///
+    /// ```
+    /// # use windle::{HandleError, WinRTWindowHandle};
+    /// # let ptr = |addr: usize| core::ptr::without_provenance_mut::<core::ffi::c_void>(addr);
+    /// let panel = WinRTWindowHandle::empty().with_swap_chain_panel(ptr(0x1000)).with_panel_index(1);
+    /// assert_eq!(panel.check_consistency(), Ok(()));
+    ///
+    /// let both = panel.with_core_window(ptr(0x2000));
+    /// assert!(matches!(both.check_consistency(), Err(HandleError::Inconsistent(_))));
+    ///
+    /// let stray_index = WinRTWindowHandle::empty().with_core_window(ptr(0x2000)).with_panel_index(1);
+    /// assert!(matches!(stray_index.check_consistency(), Err(HandleError::Inconsistent(_))));
+    /// ```
+    pub fn check_consistency(&self) -> Result<(), HandleError> {
+        if !self.core_window.is_null() && !self.swap_chain_panel.is_null() {
+            return Err(HandleError::Inconsistent(
+                "both `core_window` and `swap_chain_panel` are set",
+            ));
+        }
+        if self.swap_chain_panel.is_null() && self.panel_index != 0 {
+            return Err(HandleError::Inconsistent(
+                "`panel_index` is set without a `swap_chain_panel`",
+            ));
         }
+        Ok(())
+    }
+
+    /// Sets the `CoreWindow` this handle refers to.
+    pub fn with_core_window(mut self, core_window: *mut c_void) -> Self {
+        self.core_window = core_window;
+        self
+    }
+
+    /// Sets the `SwapChainPanel` this handle refers to.
+    pub fn with_swap_chain_panel(mut self, swap_chain_panel: *mut c_void) -> Self {
+        self.swap_chain_panel = swap_chain_panel;
+        self
+    }
+
+    /// Sets the index of the `SwapChainPanel` among its siblings.
+    pub fn with_panel_index(mut self, panel_index: u32) -> Self {
+        self.panel_index = panel_index;
+        self
+    }
+}
+
+/// Creates a handle from its `(core_window, swap_chain_panel)` fields, in that order, with a
+/// [`panel_index`](WinRTWindowHandle::panel_index) of `0`.
+///
+/// ```
+/// # use windle::WinRTWindowHandle;
+/// # let ptr = |addr: usize| core::ptr::without_provenance_mut::<core::ffi::c_void>(addr);
+/// let window_handle = WinRTWindowHandle::from((ptr(0x1000), ptr(0x2000)));
+/// assert_eq!(window_handle.core_window, ptr(0x1000));
+/// assert_eq!(window_handle.swap_chain_panel, ptr(0x2000));
+/// ```
+impl From<(*mut c_void, *mut c_void)> for WinRTWindowHandle {
+    fn from((core_window, swap_chain_panel): (*mut c_void, *mut c_void)) -> Self {
+        Self::empty()
+            .with_core_window(core_window)
+            .with_swap_chain_panel(swap_chain_panel)
+    }
+}
+
+/// Creates a handle from a non-null `CoreWindow`, so [`is_null`](WinRTWindowHandle::is_null) is
+/// guaranteed to be `false`.
+///
+/// ```
+/// # use core::ptr::NonNull;
+/// # use windle::WinRTWindowHandle;
+/// let window_handle = WinRTWindowHandle::from(NonNull::<u8>::dangling().cast());
+/// assert!(!window_handle.is_null());
+/// ```
+impl From<NonNull<c_void>> for WinRTWindowHandle {
+    fn from(core_window: NonNull<c_void>) -> Self {
+        Self::empty().with_core_window(core_window.as_ptr())
+    }
+}
+
+/// A [`WinRTWindowHandle`] borrowed from the object that keeps its `CoreWindow` alive.
+///
+/// `CoreWindow` and `SwapChainPanel` are reference-counted COM objects: the raw pointers in a
+/// [`WinRTWindowHandle`] stay valid only while someone holds a reference to them. This type ties
+/// the handle to a borrow of that owner, so the compiler rejects uses that outlive it.
+///
+/// ```
+/// # use windle::{WinRTWindowHandle, WinRTWindowHandleRef};
+/// struct App {
+///     core_window: WinRTWindowHandle,
+/// }
+///
+/// let app = App { core_window: WinRTWindowHandle::empty() };
+/// // SAFETY: `app` holds a reference to its core window for as long as it lives.
+/// let handle = unsafe { WinRTWindowHandleRef::borrow(&app, app.core_window) };
+/// assert_eq!(handle.as_raw(), app.core_window);
+/// ```
+///
+/// The borrowed handle cannot escape its owner:
+///
+/// ```compile_fail
+/// # use windle::{WinRTWindowHandle, WinRTWindowHandleRef};
+/// # struct App {
+/// #     core_window: WinRTWindowHandle,
+/// # }
+/// let handle = {
+///     let app = App { core_window: WinRTWindowHandle::empty() };
+///     unsafe { WinRTWindowHandleRef::borrow(&app, app.core_window) }
+/// };
+/// handle.as_raw();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WinRTWindowHandleRef<'a> {
+    raw: WinRTWindowHandle,
+    _owner: PhantomData<&'a ()>,
+}
+
+impl<'a> WinRTWindowHandleRef<'a> {
+    /// Borrows `raw` for as long as `owner` is borrowed.
+    ///
+    /// # Safety
+    ///
+    /// The COM objects referenced by `raw` must be kept alive by `owner` (i.e. it must hold a
+    /// reference count on them) for the whole lifetime `'a`.
+    pub unsafe fn borrow<T: ?Sized>(owner: &'a T, raw: WinRTWindowHandle) -> Self {
+        let _ = owner;
+        Self {
+            raw,
+            _owner: PhantomData,
+        }
+    }
+
+    /// Returns the underlying raw handle.
+    pub fn as_raw(&self) -> WinRTWindowHandle {
+        self.raw
+    }
+}
+
+/// Formats a pointer field as `null` or as its `0x...` address.
+struct DebugPointer(*mut c_void);
+
+impl fmt::Debug for DebugPointer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_null() {
+            f.write_str("null")
+        } else {
+            fmt::Pointer::fmt(&self.0, f)
+        }
+    }
+}
+
+/// Null pointers are printed as `null`, other pointers as their `0x...` address.
+///
+/// ```
+/// # use windle::WindowsDisplayHandle;
+/// assert_eq!(
+///     format!("{:?}", WindowsDisplayHandle::empty()),
+///     "WindowsDisplayHandle { hmonitor: null }",
+/// );
+/// ```
+impl fmt::Debug for WindowsDisplayHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WindowsDisplayHandle")
+            .field("hmonitor", &DebugPointer(self.hmonitor))
+            .finish()
+    }
+}
+
+/// Null pointers are printed as `null`, other pointers as their `0x...` address.
+///
+/// ```
+/// # use windle::Win32WindowHandle;
+/// let window_handle = Win32WindowHandle::from_hwnd(0x1000).unwrap();
+/// assert_eq!(
+///     format!("{window_handle:?}"),
+///     "Win32WindowHandle { hwnd: 0x1000, hinstance: null, composition_visual: null }",
+/// );
+/// ```
+impl fmt::Debug for Win32WindowHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Win32WindowHandle")
+            .field("hwnd", &DebugPointer(self.hwnd))
+            .field("hinstance", &DebugPointer(self.hinstance))
+            .field("composition_visual", &DebugPointer(self.composition_visual))
+            .finish()
+    }
+}
+
+/// Null pointers are printed as `null`, other pointers as their `0x...` address, and
+/// `panel_index` in decimal.
+///
+/// ```
+/// # use windle::WinRTWindowHandle;
+/// let window_handle = WinRTWindowHandle::empty()
+///     .with_swap_chain_panel(core::ptr::without_provenance_mut::<core::ffi::c_void>(0x1000))
+///     .with_panel_index(12);
+/// assert_eq!(
+///     format!("{window_handle:?}"),
+///     "WinRTWindowHandle { core_window: null, swap_chain_panel: 0x1000, panel_index: 12 }",
+/// );
+/// ```
+impl fmt::Debug for WinRTWindowHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WinRTWindowHandle")
+            .field("core_window", &DebugPointer(self.core_window))
+            .field("swap_chain_panel", &DebugPointer(self.swap_chain_panel))
+            .field("panel_index", &self.panel_index)
+            .finish()
+    }
+}
+
+#[cfg(all(windows, any(feature = "validate-os", feature = "win32-api")))]
+mod ffi {
+    use core::ffi::c_void;
+
+    #[cfg(feature = "win32-api")]
+    pub const MONITOR_DEFAULTTONEAREST: u32 = 2;
+
+    #[link(name = "user32")]
+    extern "system" {
+        #[cfg(feature = "validate-os")]
+        pub fn IsWindow(hwnd: *mut c_void) -> i32;
+        #[cfg(feature = "win32-api")]
+        pub fn MonitorFromWindow(hwnd: *mut c_void, flags: u32) -> *mut c_void;
+        #[cfg(feature = "win32-api")]
+        pub fn GetWindowDpiAwarenessContext(hwnd: *mut c_void) -> *mut c_void;
+        #[cfg(feature = "win32-api")]
+        pub fn GetActiveWindow() -> *mut c_void;
+        #[cfg(feature = "win32-api")]
+        pub fn GetForegroundWindow() -> *mut c_void;
+    }
+}
+
+#[cfg(all(test, windows, any(feature = "validate-os", feature = "win32-api")))]
+mod tests {
+    use core::ffi::c_void;
+    use core::ptr;
+
+    use super::Win32WindowHandle;
+
+    #[link(name = "user32")]
+    extern "system" {
+        fn CreateWindowExW(
+            ex_style: u32,
+            class: *const u16,
+            name: *const u16,
+            style: u32,
+            x: i32,
+            y: i32,
+            width: i32,
+            height: i32,
+            parent: *mut c_void,
+            menu: *mut c_void,
+            instance: *mut c_void,
+            param: *mut c_void,
+        ) -> *mut c_void;
+        fn DestroyWindow(hwnd: *mut c_void) -> i32;
+        #[cfg(feature = "win32-api")]
+        fn SetActiveWindow(hwnd: *mut c_void) -> *mut c_void;
+    }
+
+    const WS_OVERLAPPEDWINDOW: u32 = 0x00CF_0000;
+    const WS_VISIBLE: u32 = 0x1000_0000;
+
+    /// Creates a visible top-level window owned by the calling thread.
+    fn create_window() -> Win32WindowHandle {
+        let mut class = [0u16; 7];
+        for (dst, src) in class.iter_mut().zip("STATIC\0".encode_utf16()) {
+            *dst = src;
+        }
+
+        // SAFETY: `class` is a nul-terminated UTF-16 string naming a system window class.
+        let hwnd = unsafe {
+            CreateWindowExW(
+                0,
+                class.as_ptr(),
+                ptr::null(),
+                WS_OVERLAPPEDWINDOW | WS_VISIBLE,
+                0,
+                0,
+                100,
+                100,
+                ptr::null_mut(),
+                ptr::null_mut(),
+                ptr::null_mut(),
+                ptr::null_mut(),
+            )
+        };
+        Win32WindowHandle::from_generic(hwnd).expect("CreateWindowExW failed")
+    }
+
+    fn destroy_window(window_handle: Win32WindowHandle) {
+        // SAFETY: the window was created by `create_window` on this thread.
+        unsafe { DestroyWindow(window_handle.hwnd) };
+    }
+
+    #[cfg(feature = "win32-api")]
+    #[test]
+    fn monitor() {
+        let window_handle = create_window();
+        let display_handle = window_handle.monitor().unwrap();
+        assert!(!display_handle.hmonitor.is_null());
+        destroy_window(window_handle);
+
+        assert_eq!(Win32WindowHandle::empty().monitor(), None);
+    }
+
+    #[cfg(feature = "win32-api")]
+    #[test]
+    fn dpi_awareness_context() {
+        let window_handle = create_window();
+        assert!(window_handle.dpi_awareness_context().is_some());
+        destroy_window(window_handle);
+
+        assert_eq!(Win32WindowHandle::empty().dpi_awareness_context(), None);
+    }
+
+    #[cfg(feature = "win32-api")]
+    #[test]
+    fn active() {
+        let window_handle = create_window();
+        // SAFETY: the window belongs to this thread.
+        unsafe { SetActiveWindow(window_handle.hwnd) };
+        assert_eq!(Win32WindowHandle::active(), Some(window_handle));
+        destroy_window(window_handle);
+    }
+
+    #[cfg(feature = "validate-os")]
+    #[test]
+    fn is_window_alive() {
+        let window_handle = create_window();
+        assert!(window_handle.is_window_alive());
+        destroy_window(window_handle);
+        assert!(!window_handle.is_window_alive());
     }
 }